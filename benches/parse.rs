@@ -1,5 +1,8 @@
 use std::str::FromStr;
 
+use divan::black_box;
+use iso_currency::{Country, Currency};
+
 fn main() {
     // Run registered benchmarks.
     divan::main();
@@ -19,3 +22,69 @@ fn parse_numeric() {
 fn from_str() {
     iso_currency::Currency::from_str("SEK").unwrap();
 }
+
+#[divan::bench]
+fn symbol() -> iso_currency::CurrencySymbol {
+    black_box(Currency::CZK).symbol()
+}
+
+#[divan::bench]
+fn used_by() -> Vec<Country> {
+    black_box(Currency::EUR).used_by()
+}
+
+#[divan::bench]
+fn from_country() -> Vec<Currency> {
+    Currency::from_country(black_box(Country::IO))
+}
+
+#[cfg(feature = "bench-data")]
+mod workload {
+    use divan::{black_box, Bencher};
+    use iso_currency::{bench_data, Currency};
+
+    const LENS: &[usize] = &[100, 10_000];
+
+    #[divan::bench(args = LENS)]
+    fn parse_codes(bencher: Bencher, len: usize) {
+        let codes = bench_data::codes(len, 42);
+        bencher.bench(|| {
+            black_box(&codes)
+                .iter()
+                .filter_map(|code| Currency::from_code(code))
+                .count()
+        });
+    }
+
+    #[divan::bench(args = LENS)]
+    fn symbols(bencher: Bencher, len: usize) {
+        let currencies = bench_data::currencies(len, 42);
+        bencher.bench(|| {
+            black_box(&currencies)
+                .iter()
+                .map(|c| c.symbol().symbol.len())
+                .sum::<usize>()
+        });
+    }
+
+    #[divan::bench(args = LENS)]
+    fn scale_amounts(bencher: Bencher, len: usize) {
+        let amounts = bench_data::amounts(len, 42);
+        bencher.bench(|| {
+            black_box(&amounts)
+                .iter()
+                .map(|(currency, amount)| currency.saturating_scale(*amount, 4))
+                .fold(0_i64, i64::wrapping_add)
+        });
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[divan::bench(args = LENS)]
+    fn serde_round_trip(bencher: Bencher, len: usize) {
+        let currencies = bench_data::currencies(len, 42);
+        bencher.bench(|| {
+            let json = serde_json::to_string(black_box(&currencies)).unwrap();
+            serde_json::from_str::<Vec<Currency>>(&json).unwrap()
+        });
+    }
+}
@@ -1,14 +1,19 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
+use std::str::FromStr;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
+use unicode_normalization::UnicodeNormalization;
 
 // use Tab separated so we can easily split on a rarely used character
 static TSV_TABLE_PATH: &str = "isodata.tsv";
+static TIMELINE_TABLE_PATH: &str = "timeline.tsv";
+static ACCEPTED_TABLE_PATH: &str = "accepted.tsv";
 
 struct IsoData {
     alpha3: String,
@@ -16,11 +21,131 @@ struct IsoData {
     name: String,
     symbol: String,
     used_by: Option<Vec<String>>,
+    // same as used_by unless the issuing_alpha2 column is filled in
+    issued_by: Option<Vec<String>>,
     subunit_symbol: Option<String>,
     exponent: Option<u16>,
     is_special: bool,
     is_fund: bool,
     is_superseded: Option<String>,
+    superseded_ratio: Option<(u64, u64)>,
+    superseded_on: Option<(u16, u8, u8)>,
+    introduced: Option<(u16, u8, u8)>,
+    // (ISO 15924 code, symbol) pairs
+    script_symbols: Vec<(String, String)>,
+    blurb: Option<String>,
+}
+
+struct TimelineData {
+    country: String,
+    currency: String,
+    start: (u16, u8, u8),
+    end: Option<(u16, u8, u8)>,
+}
+
+// dates are written as YYYY-MM-DD
+fn parse_date(date: &str) -> (u16, u8, u8) {
+    let parts: Vec<&str> = date.split('-').collect();
+    let parsed = match parts.as_slice() {
+        [year, month, day] if year.len() == 4 && month.len() == 2 && day.len() == 2 => {
+            match (year.parse(), month.parse(), day.parse()) {
+                (Ok(year), Ok(month), Ok(day)) => Some((year, month, day)),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    match parsed {
+        Some((year, month, day)) if (1..=12).contains(&month) && (1..=31).contains(&day) => {
+            (year, month, day)
+        }
+        _ => panic!("Invalid date {:?}, expected YYYY-MM-DD", date),
+    }
+}
+
+fn format_date((year, month, day): (u16, u8, u8)) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn date_tokens(date: (u16, u8, u8)) -> TokenStream {
+    let (year, month, day) = date;
+    quote!(Date { year: #year, month: #month, day: #day })
+}
+
+// example 1/1000 meaning 1000 units of the old currency equal 1 unit of the new one
+fn parse_ratio(alpha3: &str, ratio: &str) -> (u64, u64) {
+    let parsed = ratio
+        .split_once('/')
+        .and_then(|(n, d)| Some((n.parse::<u64>().ok()?, d.parse::<u64>().ok()?)));
+    match parsed {
+        Some((n, d)) if n > 0 && d > 0 => (n, d),
+        _ => panic!("Invalid superseded ratio {:?} for {}", ratio, alpha3),
+    }
+}
+
+// With the compact-data feature all strings are stored once in a single
+// arena and each currency only keeps the u16 offsets into it
+#[derive(Default)]
+struct StringArena {
+    text: String,
+    spans: HashMap<String, (u16, u16)>,
+}
+
+impl StringArena {
+    fn intern(&mut self, value: &str) -> (u16, u16) {
+        if let Some(span) = self.spans.get(value) {
+            return *span;
+        }
+        let start = self.text.len();
+        self.text.push_str(value);
+        let span = (
+            u16::try_from(start).expect("String arena exceeds u16 offsets"),
+            u16::try_from(self.text.len()).expect("String arena exceeds u16 offsets"),
+        );
+        self.spans.insert(value.to_owned(), span);
+        span
+    }
+
+    // expression looking up the string of the currency `receiver`
+    fn lookup<'a>(
+        &mut self,
+        receiver: TokenStream,
+        values: impl Iterator<Item = &'a str>,
+    ) -> TokenStream {
+        let spans: Vec<TokenStream> = values
+            .map(|value| {
+                let (start, end) = self.intern(value);
+                quote!((#start, #end))
+            })
+            .collect();
+        let len = spans.len();
+        quote!({
+            static SPANS: [(u16, u16); #len] = [#(#spans),*];
+            let (start, end) = SPANS[#receiver as usize];
+            &STRING_ARENA[start as usize..end as usize]
+        })
+    }
+
+    fn lookup_optional<'a>(
+        &mut self,
+        receiver: TokenStream,
+        values: impl Iterator<Item = Option<&'a str>>,
+    ) -> TokenStream {
+        let spans: Vec<TokenStream> = values
+            .map(|value| match value {
+                Some(value) => {
+                    let (start, end) = self.intern(value);
+                    quote!(Some((#start, #end)))
+                }
+                None => quote!(None),
+            })
+            .collect();
+        let len = spans.len();
+        quote!({
+            static SPANS: [Option<(u16, u16)>; #len] = [#(#spans),*];
+            SPANS[#receiver as usize].map(|(start, end)| &STRING_ARENA[start as usize..end as usize])
+        })
+    }
 }
 
 fn parse_superseded(flag: &str) -> Option<String> {
@@ -53,7 +178,7 @@ fn parse_flags(flags: &str) -> (bool, bool, Option<String>) {
     (is_special, is_fund, is_superseded)
 }
 
-fn flags_vec(data: &IsoData) -> TokenStream {
+fn flags_slice(data: &IsoData) -> TokenStream {
     let mut flags = Vec::new();
     if data.is_special {
         flags.push(quote!(Flag::Special));
@@ -65,14 +190,35 @@ fn flags_vec(data: &IsoData) -> TokenStream {
         let currency = Ident::new(superseded, Span::call_site());
         flags.push(quote!(Flag::Superseded(Currency::#currency)));
     }
-    quote!(vec![#(#flags),*])
+    quote!(&[#(#flags),*])
+}
+
+// ISO 15924 codes and the matching `Script` variants
+static SCRIPTS: &[(&str, &str)] = &[("Arab", "Arabic"), ("Cyrl", "Cyrillic"), ("Latn", "Latin")];
+
+// example Cyrl:дин;Latn:din
+fn parse_script_symbols(currency: &str, column: &str) -> Vec<(String, String)> {
+    let mut symbols: Vec<(String, String)> = Vec::new();
+    for entry in column.split(';').filter(|e| !e.is_empty()) {
+        let (script, symbol) = entry
+            .split_once(':')
+            .unwrap_or_else(|| panic!("Invalid script symbol {:?} for {}", entry, currency));
+        if !SCRIPTS.iter().any(|(code, _)| *code == script) {
+            panic!("Unknown script {} for {}", script, currency);
+        }
+        if symbols.iter().any(|(s, _)| s == script) {
+            panic!("More than one {} symbol for {}", script, currency);
+        }
+        symbols.push((script.to_owned(), symbol.nfc().collect()));
+    }
+    symbols
 }
 
 fn read_table() -> Vec<IsoData> {
     let reader =
         BufReader::new(File::open(TSV_TABLE_PATH).expect("Couldn't read currency data table"));
 
-    reader
+    let mut isodata: Vec<IsoData> = reader
         .lines()
         .skip(1)
         .map(|line| {
@@ -80,6 +226,41 @@ fn read_table() -> Vec<IsoData> {
 
             let columns: Vec<&str> = line.split('\t').collect();
             let flags = parse_flags(columns[7]);
+            let superseded_ratio = match columns[8].is_empty() {
+                true => None,
+                false => Some(parse_ratio(columns[0], columns[8])),
+            };
+            if superseded_ratio.is_some() && flags.2.is_none() {
+                panic!("{} has a superseded ratio but isn't superseded", columns[0]);
+            }
+            let superseded_on = match columns[9].is_empty() {
+                true => None,
+                false => Some(parse_date(columns[9])),
+            };
+            if superseded_on.is_some() && flags.2.is_none() {
+                panic!("{} has a superseded date but isn't superseded", columns[0]);
+            }
+            // a year alone stands for its first day when the day isn't known
+            let introduced = match columns[10].len() {
+                0 => None,
+                4 => Some(parse_date(&format!("{}-01-01", columns[10]))),
+                _ => Some(parse_date(columns[10])),
+            };
+            if let (Some(introduced), Some(superseded_on)) = (introduced, superseded_on) {
+                if introduced >= superseded_on {
+                    panic!("{} is superseded before it was introduced", columns[0]);
+                }
+            }
+
+            let used_by = match columns[3].is_empty() {
+                true => None,
+                false => Some(
+                    columns[3]
+                        .split(';')
+                        .map(|v| v.to_owned())
+                        .collect::<Vec<String>>(),
+                ),
+            };
 
             IsoData {
                 alpha3: columns[0].into(),
@@ -87,19 +268,22 @@ fn read_table() -> Vec<IsoData> {
                     panic!("Could not parse numeric code to u16 for {}", &columns[0])
                 }),
                 name: columns[2].into(),
-                used_by: match columns[3].is_empty() {
-                    true => None,
+                used_by: used_by.clone(),
+                issued_by: match columns[12].is_empty() {
+                    true => used_by,
                     false => Some(
-                        columns[3]
+                        columns[12]
                             .split(';')
                             .map(|v| v.to_owned())
                             .collect::<Vec<String>>(),
                     ),
                 },
-                symbol: columns[4].into(),
+                // symbols are normalized to NFC so they compare equal to
+                // user input regardless of how the table was edited
+                symbol: columns[4].nfc().collect(),
                 subunit_symbol: match columns[5].is_empty() {
                     true => None,
-                    false => Some(columns[5].into()),
+                    false => Some(columns[5].nfc().collect()),
                 },
                 exponent: match columns[6].is_empty() {
                     true => None,
@@ -110,12 +294,222 @@ fn read_table() -> Vec<IsoData> {
                 is_special: flags.0,
                 is_fund: flags.1,
                 is_superseded: flags.2,
+                superseded_ratio,
+                superseded_on,
+                introduced,
+                script_symbols: parse_script_symbols(columns[0], columns[11]),
+                blurb: match columns[13].is_empty() {
+                    true => None,
+                    false => Some(columns[13].into()),
+                },
             }
         })
-        .collect()
+        .collect();
+    // the enum, and so `Currency::iter()`, is ordered by code no matter how
+    // the table is ordered
+    isodata.sort_by(|a, b| a.alpha3.cmp(&b.alpha3));
+    isodata
+}
+
+fn read_timeline(isodata: &[IsoData]) -> Vec<TimelineData> {
+    let reader = BufReader::new(
+        File::open(TIMELINE_TABLE_PATH).expect("Couldn't read currency timeline table"),
+    );
+
+    let mut timeline: Vec<TimelineData> = reader
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let line = line.expect("Problems reading line from timeline TSV file");
+            let columns: Vec<&str> = line.split('\t').collect();
+            if !isodata.iter().any(|c| c.alpha3 == columns[1]) {
+                panic!(
+                    "Unknown currency {} in timeline for {}",
+                    columns[1], columns[0]
+                );
+            }
+
+            TimelineData {
+                country: columns[0].into(),
+                currency: columns[1].into(),
+                start: parse_date(columns[2]),
+                end: match columns[3].is_empty() {
+                    true => None,
+                    false => Some(parse_date(columns[3])),
+                },
+            }
+        })
+        .collect();
+    timeline.sort_by(|a, b| (&a.country, a.start).cmp(&(&b.country, b.start)));
+    timeline
+}
+
+// (country, currency) pairs sorted by country
+fn read_accepted(isodata: &[IsoData]) -> Vec<(String, String)> {
+    let reader = BufReader::new(
+        File::open(ACCEPTED_TABLE_PATH).expect("Couldn't read accepted currencies table"),
+    );
+
+    let mut accepted: Vec<(String, String)> = reader
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let line = line.expect("Problems reading line from accepted TSV file");
+            let columns: Vec<&str> = line.split('\t').collect();
+            let currency = isodata
+                .iter()
+                .find(|c| c.alpha3 == columns[1])
+                .unwrap_or_else(|| {
+                    panic!("Unknown currency {} accepted in {}", columns[1], columns[0])
+                });
+            let official = currency
+                .used_by
+                .iter()
+                .flatten()
+                .any(|country| country == columns[0]);
+            if official {
+                panic!(
+                    "{} is already an official currency of {}",
+                    columns[1], columns[0]
+                );
+            }
+            (columns[0].to_owned(), columns[1].to_owned())
+        })
+        .collect();
+    accepted.sort();
+    accepted
+}
+
+// every country has to exist in the linked iso_country version, otherwise
+// the generated code doesn't compile with a confusing error
+fn check_countries(
+    isodata: &mut [IsoData],
+    timeline: &mut Vec<TimelineData>,
+    accepted: &mut Vec<(String, String)>,
+) {
+    let skip = env::var_os("CARGO_FEATURE_SKIP_UNKNOWN_COUNTRIES").is_some();
+    let known = |country: &str, row: String| {
+        if iso_country::Country::from_str(country).is_ok() {
+            return true;
+        }
+        if !skip {
+            panic!(
+                "Unknown country {} in {}, update iso_country or enable the skip-unknown-countries feature",
+                country, row
+            );
+        }
+        println!(
+            "cargo:warning=Skipping unknown country {} in {}",
+            country, row
+        );
+        false
+    };
+
+    for currency in isodata.iter_mut() {
+        let row = format!("{} row {}", TSV_TABLE_PATH, currency.alpha3);
+        for countries in [&mut currency.used_by, &mut currency.issued_by].iter_mut() {
+            if let Some(list) = countries.as_mut() {
+                list.retain(|country| known(country, row.clone()));
+                if list.is_empty() {
+                    **countries = None;
+                }
+            }
+        }
+    }
+    timeline.retain(|p| {
+        known(
+            &p.country,
+            format!("{} row {} {}", TIMELINE_TABLE_PATH, p.country, p.currency),
+        )
+    });
+    accepted.retain(|(country, currency)| {
+        known(
+            country,
+            format!("{} row {} {}", ACCEPTED_TABLE_PATH, country, currency),
+        )
+    });
+}
+
+// (legacy id, currency) pairs sorted by id, read from the file given in
+// ISO_CURRENCY_LEGACY_IDS. Every currency has to be mapped, so code
+// relying on the ids can't silently miss one.
+fn read_legacy_ids(isodata: &[IsoData]) -> Vec<(u32, String)> {
+    let path = match env::var("ISO_CURRENCY_LEGACY_IDS") {
+        Ok(path) if !path.trim().is_empty() => path,
+        _ => return Vec::new(),
+    };
+    println!("cargo:rerun-if-changed={path}");
+    let reader = BufReader::new(
+        File::open(&path)
+            .unwrap_or_else(|e| panic!("Couldn't read legacy id table {}: {}", path, e)),
+    );
+
+    let mut legacy_ids: Vec<(u32, String)> = Vec::new();
+    for line in reader.lines().skip(1) {
+        let line = line.expect("Problems reading line from legacy id TSV file");
+        if line.trim().is_empty() {
+            continue;
+        }
+        let columns: Vec<&str> = line.split('\t').collect();
+        if columns.len() != 2 {
+            panic!("Invalid line {:?} in legacy id table {}", line, path);
+        }
+        let id = columns[1]
+            .trim()
+            .parse::<u32>()
+            .unwrap_or_else(|_| panic!("Could not parse legacy id to u32 for {}", columns[0]));
+        legacy_ids.push((id, columns[0].trim().to_owned()));
+    }
+
+    let mut currencies: Vec<&str> = legacy_ids.iter().map(|(_, c)| c.as_str()).collect();
+    currencies.sort();
+    for pair in currencies.windows(2) {
+        if pair[0] == pair[1] {
+            panic!("{} has more than one legacy id", pair[0]);
+        }
+    }
+    // currencies left out with ISO_CURRENCY_COUNTRIES may still be mapped
+    for (_, currency) in legacy_ids.iter() {
+        if !isodata.iter().any(|c| &c.alpha3 == currency) {
+            panic!("Unknown currency {} in legacy id table", currency);
+        }
+    }
+    legacy_ids.sort();
+    for pair in legacy_ids.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            panic!(
+                "{} and {} have the same legacy id {}",
+                pair[0].1, pair[1].1, pair[0].0
+            );
+        }
+    }
+    legacy_ids
+}
+
+fn write_legacy_ids(file: &mut impl Write, data: &[IsoData], legacy_ids: &[(u32, String)]) {
+    if !legacy_ids.is_empty() {
+        for currency in data.iter() {
+            if !legacy_ids.iter().any(|(_, c)| c == &currency.alpha3) {
+                panic!("{} has no legacy id", currency.alpha3);
+            }
+        }
+    }
+    let entries: TokenStream = legacy_ids
+        .iter()
+        .filter(|(_, currency)| data.iter().any(|c| &c.alpha3 == currency))
+        .map(|(id, currency)| {
+            let currency = Ident::new(currency, Span::call_site());
+            quote!((#id, Currency::#currency),)
+        })
+        .collect();
+    let outline = quote!(
+        static LEGACY_IDS: &[(u32, Currency)] = &[#entries];
+    );
+
+    write!(file, "{}", outline).unwrap();
 }
 
-fn write_enum(file: &mut BufWriter<File>, data: &[IsoData]) {
+fn write_enum(file: &mut impl Write, data: &[IsoData]) {
     let body: TokenStream = data
         .iter()
         .map(|currency| {
@@ -128,10 +522,23 @@ fn write_enum(file: &mut BufWriter<File>, data: &[IsoData]) {
         })
         .collect();
     let outline = quote! {
+        /// An ISO 4217 currency
+        ///
+        /// The variants are ordered by code, whatever the order of the data
+        /// table or the enabled features, so `Currency::iter()`, the `Ord`
+        /// implementation and `Currency::dense_index` all follow the
+        /// alphabetical order of the codes. Use `Currency::iter_numeric_order`
+        /// for the order of the numeric codes.
         #[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
         #[cfg_attr(feature = "iterator", derive(EnumIter))]
         #[cfg_attr(feature = "with-schemars", derive(JsonSchema))]
-        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(kani, derive(kani::Arbitrary))]
+        #[cfg_attr(
+            feature = "with-rkyv",
+            derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+            rkyv(derive(Debug, Clone, Copy, PartialEq, Eq, Hash))
+        )]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
         pub enum Currency {
             #body
         }
@@ -171,59 +578,194 @@ fn generate_numeric_method(data: &[IsoData]) -> TokenStream {
     }
 }
 
-fn name_method(data: &[IsoData]) -> TokenStream {
+fn name_method(data: &[IsoData], arena: Option<&mut StringArena>) -> TokenStream {
+    let body = match arena {
+        Some(arena) => arena.lookup(quote!(*self), data.iter().map(|c| c.name.as_str())),
+        None => {
+            let match_arms: TokenStream = data
+                .iter()
+                .map(|currency| {
+                    let variant = Ident::new(&currency.alpha3, Span::call_site());
+                    let name = currency.name.as_str();
+                    quote! {
+                        Currency::#variant => #name,
+                    }
+                })
+                .collect();
+            quote!(match self {
+                #match_arms
+            })
+        }
+    };
+    quote! {
+        /// Returns the name of the currency in English
+        ///
+        /// This method will return the English name of the currency
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// assert_eq!(Currency::EUR.name(), "Euro");
+        /// ```
+        pub fn name(&self) -> &str {
+            #body
+        }
+    }
+}
+
+fn code_method(data: &[IsoData], arena: Option<&mut StringArena>) -> TokenStream {
+    let body = match arena {
+        Some(arena) => arena.lookup(quote!(*self), data.iter().map(|c| c.alpha3.as_str())),
+        None => {
+            let match_arms: TokenStream = data
+                .iter()
+                .map(|currency| {
+                    let variant = Ident::new(&currency.alpha3, Span::call_site());
+                    let code = currency.alpha3.as_str();
+                    quote! {
+                        Currency::#variant => #code,
+                    }
+                })
+                .collect();
+            quote!(match self {
+                #match_arms
+            })
+        }
+    };
+    quote! {
+        /// Returns the ISO 4217 code
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// assert_eq!(Currency::EUR.code(), "EUR");
+        /// ```
+        pub fn code(&self) -> &'static str {
+            #body
+        }
+    }
+}
+
+fn code_lowercase_method(data: &[IsoData]) -> TokenStream {
     let match_arms: TokenStream = data
         .iter()
         .map(|currency| {
             let variant = Ident::new(&currency.alpha3, Span::call_site());
-            let name = currency.name.as_str();
+            let code = currency.alpha3.to_lowercase();
             quote! {
-                Currency::#variant => #name,
+                Currency::#variant => #code,
             }
         })
         .collect();
     quote! {
-        /// Returns the name of the currency in English
+        /// Returns the ISO 4217 code in lowercase
         ///
-        /// This method will return the English name of the currency
+        /// The lowercase codes are precomputed, so this doesn't allocate.
         ///
         /// # Example
         ///
         /// ```
         /// use iso_currency::Currency;
         ///
-        /// assert_eq!(Currency::EUR.name(), "Euro");
+        /// assert_eq!(Currency::EUR.code_lowercase(), "eur");
         /// ```
-        pub fn name(&self) -> &str {
+        pub fn code_lowercase(&self) -> &'static str {
             match self {
                 #match_arms
             }
         }
+
+        /// Returns the currency as a URL path segment
+        ///
+        /// The segment is the lowercase ISO 4217 code, which only contains
+        /// characters that don't need percent-encoding. Use
+        /// `Currency::from_url_segment` to parse it back.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// let route = format!("/prices/{}/{}", Currency::EUR.as_url_segment(), Currency::USD.as_url_segment());
+        /// assert_eq!(route, "/prices/eur/usd");
+        /// ```
+        pub fn as_url_segment(&self) -> &'static str {
+            self.code_lowercase()
+        }
     }
 }
 
-fn code_method(data: &[IsoData]) -> TokenStream {
+fn from_url_segment_method(data: &[IsoData]) -> TokenStream {
     let match_arms: TokenStream = data
         .iter()
         .map(|currency| {
+            let code = currency.alpha3.to_lowercase();
             let variant = Ident::new(&currency.alpha3, Span::call_site());
-            let code = currency.alpha3.as_str();
             quote! {
-                Currency::#variant => #code,
+                #code => Some(Currency::#variant),
+            }
+        })
+        .collect();
+    quote!(
+        /// Create a currency instance from a URL path segment
+        ///
+        /// Accepts the lowercase ISO 4217 code as returned by
+        /// `Currency::as_url_segment` as well as the regular uppercase code.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// assert_eq!(Currency::from_url_segment("eur"), Some(Currency::EUR));
+        /// assert_eq!(Currency::from_url_segment("EUR"), Some(Currency::EUR));
+        /// assert_eq!(Currency::from_url_segment("Eur"), None);
+        /// ```
+        pub fn from_url_segment(segment: &str) -> Option<Currency> {
+            if segment.len() != 3 {
+                return None;
+            }
+            match segment {
+                #match_arms
+                _ => Currency::from_code(segment),
+            }
+        }
+    )
+}
+
+fn stable_hash64_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let hash = fnv1a64(currency.alpha3.as_bytes());
+            quote! {
+                Currency::#variant => #hash,
             }
         })
         .collect();
     quote! {
-        /// Returns the ISO 4217 code
+        /// Returns a hash of the currency which is stable across languages and versions
+        ///
+        /// The hash is the 64 bit FNV-1a hash of the ASCII bytes of the ISO 4217
+        /// code (offset basis `0xcbf29ce484222325`, prime `0x100000001b3`),
+        /// precomputed at build time. Unlike `std::hash::Hash` it is guaranteed
+        /// to never change, so it can be used to shard data by currency
+        /// consistently between services written in different languages.
         ///
         /// # Example
         ///
         /// ```
         /// use iso_currency::Currency;
         ///
-        /// assert_eq!(Currency::EUR.code(), "EUR");
+        /// assert_eq!(Currency::EUR.stable_hash64(), 0xd97d17198e7bf8c7);
+        /// let shard = Currency::EUR.stable_hash64() % 16;
         /// ```
-        pub fn code(&self) -> &'static str {
+        pub fn stable_hash64(self) -> u64 {
             match self {
                 #match_arms
             }
@@ -278,21 +820,84 @@ fn used_by_method(data: &[IsoData]) -> TokenStream {
     }
 }
 
-fn symbol_method(data: &[IsoData]) -> TokenStream {
+fn issuing_countries_method(data: &[IsoData]) -> TokenStream {
     let match_arms: TokenStream = data
         .iter()
         .map(|currency| {
-            let variant = Ident::new(&currency.alpha3, Span::call_site());
-            let symbol = currency.symbol.as_str();
-            let subunit_symbol = match currency.subunit_symbol {
-                Some(ref v) => quote!(Some(#v)),
-                None => quote!(None),
+            let country_list: TokenStream = match &currency.issued_by {
+                Some(v) => v
+                    .iter()
+                    .map(|c| {
+                        let country_ident = Ident::new(c, Span::call_site());
+                        quote!(Country::#country_ident,)
+                    })
+                    .collect(),
+                None => quote!(),
             };
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
             quote! {
-                Currency::#variant => CurrencySymbol::new(#symbol, #subunit_symbol),
+                Currency::#variant => vec![#country_list],
             }
         })
         .collect();
+    quote! {
+        /// Returns a list of locations which issue the currency
+        ///
+        /// Unlike `used_by`, this only lists the jurisdictions whose
+        /// authorities issue the currency, e.g. `USD` is used in the British
+        /// Indian Ocean Territory but only issued by the United States.
+        /// Currencies issued jointly by a monetary union list all of its
+        /// members. The list of locations is sorted.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Country};
+        ///
+        /// assert!(Currency::USD.used_by().contains(&Country::IO));
+        /// assert_eq!(Currency::USD.issuing_countries(), vec![Country::US]);
+        /// assert_eq!(Currency::JPY.issuing_countries(), Currency::JPY.used_by());
+        /// ```
+        pub fn issuing_countries(self) -> Vec<Country> {
+            let mut territories = match self {
+                #match_arms
+            };
+            territories.sort();
+            territories
+        }
+    }
+}
+
+fn symbol_method(data: &[IsoData], arena: Option<&mut StringArena>) -> TokenStream {
+    let body = match arena {
+        Some(arena) => {
+            let symbol = arena.lookup(quote!(self), data.iter().map(|c| c.symbol.as_str()));
+            let subunit_symbol = arena.lookup_optional(
+                quote!(self),
+                data.iter().map(|c| c.subunit_symbol.as_deref()),
+            );
+            quote!(CurrencySymbol::new(#symbol, #subunit_symbol))
+        }
+        None => {
+            let match_arms: TokenStream = data
+                .iter()
+                .map(|currency| {
+                    let variant = Ident::new(&currency.alpha3, Span::call_site());
+                    let symbol = currency.symbol.as_str();
+                    let subunit_symbol = match currency.subunit_symbol {
+                        Some(ref v) => quote!(Some(#v)),
+                        None => quote!(None),
+                    };
+                    quote! {
+                        Currency::#variant => CurrencySymbol::new(#symbol, #subunit_symbol),
+                    }
+                })
+                .collect();
+            quote!(match self {
+                #match_arms
+            })
+        }
+    };
     quote! (
         /// Returns the currency's symbol
         ///
@@ -300,6 +905,9 @@ fn symbol_method(data: &[IsoData]) -> TokenStream {
         /// currency. In case there is no symbol associated the international
         /// currency symbol will be returned.
         ///
+        /// The symbol and subunit symbol are guaranteed to be in Unicode
+        /// Normalization Form C (NFC).
+        ///
         /// # Example
         ///
         /// ```
@@ -309,9 +917,7 @@ fn symbol_method(data: &[IsoData]) -> TokenStream {
         /// assert_eq!(format!("{}", Currency::XXX.symbol()), "¤");
         /// ```
         pub fn symbol(self) -> CurrencySymbol {
-            match self {
-                #match_arms
-            }
+            #body
         }
     )
 }
@@ -349,10 +955,45 @@ fn from_code_method(data: &[IsoData]) -> TokenStream {
     )
 }
 
-fn from_numeric_method(data: &[IsoData]) -> TokenStream {
-    let match_arms: TokenStream = data
+fn from_name_method(data: &[IsoData]) -> TokenStream {
+    // some currencies share their name with the currency superseding them,
+    // the current one wins
+    let mut names: Vec<(&str, &IsoData)> = Vec::new();
+    for currency in data {
+        match names.iter_mut().find(|(name, _)| *name == currency.name) {
+            Some(entry) if entry.1.is_superseded.is_some() => entry.1 = currency,
+            Some(_) => {}
+            None => names.push((&currency.name, currency)),
+        }
+    }
+    let match_arms: TokenStream = names
         .iter()
-        .map(|currency| {
+        .map(|(name, currency)| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            quote! {
+                #name => Some(Currency::#variant),
+            }
+        })
+        .collect();
+    quote!(
+        /// Create a currency instance from its English name
+        ///
+        /// If several currencies share a name the one which isn't superseded
+        /// is returned.
+        #[cfg(feature = "with-serde_with")]
+        pub(crate) fn from_name(name: &str) -> Option<Currency> {
+            match name {
+                #match_arms
+                _ => None,
+            }
+        }
+    )
+}
+
+fn from_numeric_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .map(|currency| {
             let numeric_code = currency.numeric;
             let variant = Ident::new(&currency.alpha3, Span::call_site());
             quote! {
@@ -417,6 +1058,163 @@ fn exponent_method(data: &[IsoData]) -> TokenStream {
     )
 }
 
+fn symbol_for_script_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .flat_map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            currency.script_symbols.iter().map(move |(script, symbol)| {
+                let script = SCRIPTS.iter().find(|(code, _)| code == script).unwrap().1;
+                let script = Ident::new(script, Span::call_site());
+                quote! {
+                    (Currency::#variant, Script::#script) => Some(#symbol),
+                }
+            })
+        })
+        .collect();
+    quote!(
+        /// Returns the symbol of the currency written in the given script
+        ///
+        /// Only currencies whose symbol differs between the scripts used in
+        /// their region have script specific symbols, all others return `None`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Script};
+        ///
+        /// assert_eq!(Currency::RSD.symbol_for_script(Script::Cyrillic), Some("дин"));
+        /// assert_eq!(Currency::RSD.symbol_for_script(Script::Latin), Some("din"));
+        /// assert_eq!(Currency::EUR.symbol_for_script(Script::Latin), None);
+        /// ```
+        pub fn symbol_for_script(self, script: Script) -> Option<&'static str> {
+            match (self, script) {
+                #match_arms
+                _ => None,
+            }
+        }
+    )
+}
+
+fn with_exponent_method(data: &[IsoData]) -> TokenStream {
+    let mut exponents: Vec<u16> = data.iter().filter_map(|c| c.exponent).collect();
+    exponents.sort();
+    exponents.dedup();
+
+    let match_arms: TokenStream = exponents
+        .iter()
+        .map(|exponent| {
+            let currencies: TokenStream = data
+                .iter()
+                .filter(|c| c.exponent == Some(*exponent))
+                .map(|c| {
+                    let variant = Ident::new(&c.alpha3, Span::call_site());
+                    quote!(Currency::#variant,)
+                })
+                .collect();
+            let exponent = *exponent as u8;
+            quote! {
+                #exponent => &[#currencies],
+            }
+        })
+        .collect();
+    quote!(
+        /// Returns all currencies with the given exponent
+        ///
+        /// Currencies without a minor unit (e.g. gold) are never returned.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// assert!(Currency::with_exponent(0).contains(&Currency::JPY));
+        /// assert!(Currency::with_exponent(3).contains(&Currency::KWD));
+        /// assert!(Currency::with_exponent(7).is_empty());
+        /// ```
+        pub fn with_exponent(exponent: u8) -> &'static [Currency] {
+            match exponent {
+                #match_arms
+                _ => &[],
+            }
+        }
+    )
+}
+
+// precious metals have no flag of their own in the table
+static METAL_CODES: &[&str] = &["XAG", "XAU", "XPD", "XPT"];
+
+fn group_consts(data: &[IsoData]) -> TokenStream {
+    let group = |doc: &str, name: &str, filter: &dyn Fn(&IsoData) -> bool| {
+        let name = Ident::new(name, Span::call_site());
+        let currencies: TokenStream = data
+            .iter()
+            .filter(|c| filter(c))
+            .map(|c| {
+                let variant = Ident::new(&c.alpha3, Span::call_site());
+                quote!(Currency::#variant,)
+            })
+            .collect();
+        quote! {
+            #[doc = #doc]
+            pub const #name: &'static [Currency] = &[#currencies];
+        }
+    };
+
+    let all: TokenStream = data
+        .iter()
+        .map(|c| {
+            let variant = Ident::new(&c.alpha3, Span::call_site());
+            quote!(Currency::#variant,)
+        })
+        .collect();
+    let mut by_numeric: Vec<&IsoData> = data.iter().collect();
+    by_numeric.sort_by_key(|c| c.numeric);
+    let numeric_order: TokenStream = by_numeric
+        .iter()
+        .map(|c| {
+            let variant = Ident::new(&c.alpha3, Span::call_site());
+            quote!(Currency::#variant,)
+        })
+        .collect();
+    let all = quote! {
+        /// All currencies ordered by code
+        pub(crate) const VARIANTS: &'static [Currency] = &[#all];
+
+        /// All currencies ordered by numeric code
+        pub(crate) const NUMERIC_ORDER: &'static [Currency] = &[#numeric_order];
+    };
+
+    vec![
+        all,
+        group(
+            "All currencies without a minor unit",
+            "ZERO_DECIMAL",
+            &|c| c.exponent == Some(0),
+        ),
+        group(
+            "All currencies with 3 decimal places",
+            "THREE_DECIMAL",
+            &|c| c.exponent == Some(3),
+        ),
+        group(
+            "All currencies with 4 decimal places",
+            "FOUR_DECIMAL",
+            &|c| c.exponent == Some(4),
+        ),
+        group("All funds", "FUNDS", &|c| c.is_fund),
+        group("All precious metals", "METALS", &|c| {
+            METAL_CODES.contains(&c.alpha3.as_str())
+        }),
+        group("All special currencies", "SPECIAL", &|c| c.is_special),
+        group("All superseded currencies", "SUPERSEDED", &|c| {
+            c.is_superseded.is_some()
+        }),
+    ]
+    .into_iter()
+    .collect()
+}
+
 fn subunit_fraction_method(data: &[IsoData]) -> TokenStream {
     let match_arms: TokenStream = data
         .iter()
@@ -455,6 +1253,9 @@ fn subunit_fraction_method(data: &[IsoData]) -> TokenStream {
 }
 
 fn joint_match_currency_bool(data: &[&IsoData], value: bool) -> TokenStream {
+    if data.is_empty() {
+        return TokenStream::new();
+    }
     let list: Vec<_> = data
         .iter()
         .map(|currency| {
@@ -472,14 +1273,8 @@ fn joint_match_currency_bool(data: &[&IsoData], value: bool) -> TokenStream {
 
 fn is_fund_method(data: &[IsoData]) -> TokenStream {
     let partitions: (Vec<_>, Vec<_>) = data.iter().partition(|c| c.is_fund);
-    let left_match_arms = joint_match_currency_bool(
-        partitions.0.as_slice(),
-        partitions.0.first().unwrap().is_fund,
-    );
-    let right_match_arms = joint_match_currency_bool(
-        partitions.1.as_slice(),
-        partitions.1.first().unwrap().is_fund,
-    );
+    let left_match_arms = joint_match_currency_bool(partitions.0.as_slice(), true);
+    let right_match_arms = joint_match_currency_bool(partitions.1.as_slice(), false);
 
     quote!(
         /// Returns true if the currency is a fund
@@ -494,14 +1289,8 @@ fn is_fund_method(data: &[IsoData]) -> TokenStream {
 
 fn is_special_method(data: &[IsoData]) -> TokenStream {
     let partitions: (Vec<_>, Vec<_>) = data.iter().partition(|c| c.is_special);
-    let left_match_arms = joint_match_currency_bool(
-        partitions.0.as_slice(),
-        partitions.0.first().unwrap().is_special,
-    );
-    let right_match_arms = joint_match_currency_bool(
-        partitions.1.as_slice(),
-        partitions.1.first().unwrap().is_special,
-    );
+    let left_match_arms = joint_match_currency_bool(partitions.0.as_slice(), true);
+    let right_match_arms = joint_match_currency_bool(partitions.1.as_slice(), false);
 
     quote!(
         /// Returns true if the currency is a special currency
@@ -548,6 +1337,240 @@ fn is_superseded_method(data: &[IsoData]) -> TokenStream {
     )
 }
 
+fn kind_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let kind = if currency.is_superseded.is_some() {
+                quote!(Kind::Superseded)
+            } else if currency.is_special {
+                quote!(Kind::Special)
+            } else if currency.is_fund {
+                quote!(Kind::Fund)
+            } else {
+                quote!(Kind::Regular)
+            };
+            quote! {
+                Currency::#variant => #kind,
+            }
+        })
+        .collect();
+    quote!(
+        /// Returns the kind of the currency
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Kind};
+        ///
+        /// assert_eq!(Currency::EUR.kind(), Kind::Regular);
+        /// assert_eq!(Currency::BOV.kind(), Kind::Fund);
+        /// assert_eq!(Currency::XAU.kind(), Kind::Special);
+        /// assert_eq!(Currency::HRK.kind(), Kind::Superseded);
+        /// ```
+        pub fn kind(self) -> Kind {
+            match self {
+                #match_arms
+            }
+        }
+    )
+}
+
+fn superseded_on_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .filter_map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let date = date_tokens(currency.superseded_on?);
+            Some(quote! {
+                Currency::#variant => Some(#date),
+            })
+        })
+        .collect();
+    quote!(
+        /// Returns the date from which the currency was superseded
+        ///
+        /// This returns `None` for currencies which aren't superseded or if
+        /// the date isn't known.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Date};
+        ///
+        /// assert_eq!(Currency::HRK.superseded_on(), Some(Date::new(2023, 1, 1)));
+        /// assert_eq!(Currency::EUR.superseded_on(), None);
+        /// ```
+        pub fn superseded_on(self) -> Option<Date> {
+            match self {
+                #match_arms
+                _ => None,
+            }
+        }
+    )
+}
+
+fn introduced_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .filter_map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let date = date_tokens(currency.introduced?);
+            Some(quote! {
+                Currency::#variant => Some(#date),
+            })
+        })
+        .collect();
+    quote!(
+        /// Returns the date on which the currency was introduced
+        ///
+        /// This returns `None` if the date isn't known.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Date};
+        ///
+        /// assert_eq!(Currency::EUR.introduced(), Some(Date::new(1999, 1, 1)));
+        /// assert_eq!(Currency::XTS.introduced(), None);
+        /// ```
+        pub fn introduced(self) -> Option<Date> {
+            match self {
+                #match_arms
+                _ => None,
+            }
+        }
+    )
+}
+
+fn blurb_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
+        .iter()
+        .filter_map(|currency| {
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let blurb = currency.blurb.as_deref()?;
+            Some(quote! {
+                Currency::#variant => Some(#blurb),
+            })
+        })
+        .collect();
+    quote!(
+        /// Returns a one sentence description of the currency
+        ///
+        /// Meant for help texts and tooltips. Every currency which isn't a
+        /// fund, special or superseded currency has a description, for the
+        /// others this may return `None`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::Currency;
+        ///
+        /// assert_eq!(
+        ///     Currency::XAU.blurb(),
+        ///     Some("One troy ounce of gold, used for pricing and trading the metal.")
+        /// );
+        /// assert_eq!(Currency::BOV.blurb(), None);
+        /// ```
+        pub fn blurb(self) -> Option<&'static str> {
+            match self {
+                #match_arms
+                _ => None,
+            }
+        }
+    )
+}
+
+// screaming snake case of the English name, e.g. POUND_STERLING, with
+// parenthesized parts left out and letters reduced to ASCII
+fn alias_name(name: &str) -> String {
+    let mut alias = String::new();
+    let mut in_parens = false;
+    for c in name.nfkd() {
+        let ascii = match c {
+            '(' => {
+                in_parens = true;
+                continue;
+            }
+            ')' => {
+                in_parens = false;
+                continue;
+            }
+            _ if in_parens => continue,
+            'ł' | 'Ł' => "L".to_owned(),
+            'đ' | 'Đ' => "D".to_owned(),
+            'ø' | 'Ø' => "O".to_owned(),
+            c if c.is_ascii_alphanumeric() => c.to_ascii_uppercase().to_string(),
+            // combining marks left over from the decomposition
+            c if !c.is_ascii() => continue,
+            _ => "_".to_owned(),
+        };
+        if ascii == "_" && (alias.is_empty() || alias.ends_with('_')) {
+            continue;
+        }
+        alias.push_str(&ascii);
+    }
+    alias.trim_end_matches('_').to_owned()
+}
+
+fn name_aliases(data: &[IsoData]) -> TokenStream {
+    let mut aliases: Vec<(String, Vec<&IsoData>)> = Vec::new();
+    for currency in data {
+        let alias = alias_name(&currency.name);
+        if alias.is_empty() || alias.starts_with(|c: char| c.is_ascii_digit()) {
+            panic!(
+                "The name of {} can't be turned into an alias",
+                currency.alpha3
+            );
+        }
+        match aliases.iter_mut().find(|(a, _)| *a == alias) {
+            Some((_, currencies)) => currencies.push(currency),
+            None => aliases.push((alias, vec![currency])),
+        }
+    }
+
+    aliases
+        .into_iter()
+        .map(|(alias, currencies)| {
+            // a name shared with superseded currencies or funds refers to the
+            // regular currency
+            let currency = match currencies.len() {
+                1 => currencies[0],
+                _ => {
+                    let regular: Vec<&IsoData> = currencies
+                        .iter()
+                        .copied()
+                        .filter(|c| !c.is_fund && !c.is_special && c.is_superseded.is_none())
+                        .collect();
+                    match regular.len() {
+                        1 => regular[0],
+                        _ => panic!(
+                            "The names of {} all map to the alias {}",
+                            currencies
+                                .iter()
+                                .map(|c| c.alpha3.as_str())
+                                .collect::<Vec<&str>>()
+                                .join(", "),
+                            alias
+                        ),
+                    }
+                }
+            };
+            let doc = format!(
+                "Alias of `Currency::{}` ({})",
+                currency.alpha3, currency.name
+            );
+            let alias = Ident::new(&alias, Span::call_site());
+            let variant = Ident::new(&currency.alpha3, Span::call_site());
+            quote! {
+                #[doc = #doc]
+                pub const #alias: Currency = Currency::#variant;
+            }
+        })
+        .collect()
+}
+
 fn latest_method(data: &[IsoData]) -> TokenStream {
     let match_arms: TokenStream = data
         .iter()
@@ -582,40 +1605,71 @@ fn latest_method(data: &[IsoData]) -> TokenStream {
     )
 }
 
-fn flags_method(isodata: &[IsoData]) -> TokenStream {
-    let match_arms: TokenStream = isodata
+fn latest_with_factor_method(data: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = data
         .iter()
+        .filter(|c| c.is_superseded.is_some())
         .map(|currency| {
             let variant = Ident::new(&currency.alpha3, Span::call_site());
-            let flags = flags_vec(currency);
+            let latest = Ident::new(currency.is_superseded.as_ref().unwrap(), Span::call_site());
+            let ratio = match currency.superseded_ratio {
+                Some((numerator, denominator)) => {
+                    quote!(Some(Ratio { numerator: #numerator, denominator: #denominator }))
+                }
+                None => quote!(None),
+            };
             quote! {
-                Currency::#variant => #flags,
+                Currency::#variant => (Currency::#latest, #ratio),
             }
         })
         .collect();
     quote!(
-        /// Returns a list of extra information flags about the currency"
-        pub fn flags(self) -> Vec<Flag> {
+        /// Returns the latest currency together with the factor to convert amounts to it
+        ///
+        /// Multiplying an amount of this currency by the returned ratio gives
+        /// the equivalent amount in the latest currency. The ratio is `None` if
+        /// the currency isn't superseded or if the conversion factor is unknown.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Ratio};
+        ///
+        /// assert_eq!(Currency::VES.latest_with_factor(), (Currency::VED, Some(Ratio::new(1, 1_000_000))));
+        /// assert_eq!(Currency::EUR.latest_with_factor(), (Currency::EUR, None));
+        /// ```
+        pub fn latest_with_factor(self) -> (Self, Option<Ratio>) {
             match self {
                 #match_arms
+                _ => (self, None),
             }
         }
     )
 }
 
-fn has_flag_method(data: &[IsoData]) -> TokenStream {
-    let match_arms: TokenStream = data
+fn flags_method(isodata: &[IsoData]) -> TokenStream {
+    let match_arms: TokenStream = isodata
         .iter()
         .map(|currency| {
             let variant = Ident::new(&currency.alpha3, Span::call_site());
+            let flags = flags_slice(currency);
             quote! {
-                Currency::#variant => Currency::#variant.flags().contains(&flag),
+                Currency::#variant => #flags,
             }
         })
         .collect();
     quote!(
-        /// Returns true if the currency has the given flag
-        pub fn has_flag(self, flag: Flag) -> bool {
+        /// Returns a list of extra information flags about the currency
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Flag};
+        ///
+        /// assert_eq!(Currency::VES.flags(), &[Flag::Superseded(Currency::VED)]);
+        /// assert!(Currency::EUR.flags().is_empty());
+        /// ```
+        pub fn flags(self) -> &'static [Flag] {
             match self {
                 #match_arms
             }
@@ -623,10 +1677,37 @@ fn has_flag_method(data: &[IsoData]) -> TokenStream {
     )
 }
 
+fn has_flag_method() -> TokenStream {
+    quote!(
+        /// Returns true if the currency has the given flag
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{Currency, Flag};
+        ///
+        /// assert!(Currency::BOV.has_flag(Flag::Fund));
+        /// assert!(!Currency::VES.has_flag(Flag::Superseded(Currency::EUR)));
+        /// ```
+        pub fn has_flag(self, flag: Flag) -> bool {
+            match flag {
+                Flag::Fund => self.is_fund(),
+                Flag::Special => self.is_special(),
+                Flag::Superseded(successor) => self.is_superseded() == Some(successor),
+            }
+        }
+    )
+}
+
 fn from_country_method(country_map: &HashMap<String, Vec<String>>) -> TokenStream {
-    let match_arms: TokenStream = country_map
+    // sorted so the generated file is the same on every build
+    let mut countries: Vec<&String> = country_map.keys().collect();
+    countries.sort();
+
+    let match_arms: TokenStream = countries
         .iter()
-        .map(|(country, currencies)| {
+        .map(|country| {
+            let currencies = &country_map[*country];
             let country = Ident::new(country, Span::call_site());
             let currency_vec: TokenStream = currencies
                 .iter()
@@ -649,67 +1730,588 @@ fn from_country_method(country_map: &HashMap<String, Vec<String>>) -> TokenStrea
     )
 }
 
-fn write_enum_impl(
-    file: &mut BufWriter<File>,
+// One generated file per method, each in its own `impl Currency` block
+fn enum_impl_files(
     data: &[IsoData],
     country_map: &HashMap<String, Vec<String>>,
-) {
-    let numeric_method = generate_numeric_method(data);
-    let name_method = name_method(data);
-    let code_method = code_method(data);
-    let used_by_method = used_by_method(data);
-    let symbol_method = symbol_method(data);
-    let from_code_method = from_code_method(data);
-    let from_numeric_method = from_numeric_method(data);
-    let exponent_method = exponent_method(data);
-    let subunit_fraction_method = subunit_fraction_method(data);
-    let is_fund_method = is_fund_method(data);
-    let is_special_method = is_special_method(data);
-    let is_superseded_method = is_superseded_method(data);
-    let latest_method = latest_method(data);
-    let flags_method = flags_method(data);
-    let has_flag_method = has_flag_method(data);
-    let from_country_method = from_country_method(country_map);
+) -> Vec<(String, Vec<u8>)> {
+    let mut arena = env::var_os("CARGO_FEATURE_COMPACT_DATA").map(|_| StringArena::default());
+    let mut methods: Vec<(&str, TokenStream)> = vec![
+        ("group_consts", group_consts(data)),
+        ("numeric", generate_numeric_method(data)),
+        ("name", name_method(data, arena.as_mut())),
+        ("code", code_method(data, arena.as_mut())),
+        ("code_lowercase", code_lowercase_method(data)),
+        ("stable_hash64", stable_hash64_method(data)),
+        ("used_by", used_by_method(data)),
+        ("issuing_countries", issuing_countries_method(data)),
+        ("symbol", symbol_method(data, arena.as_mut())),
+        ("from_code", from_code_method(data)),
+        ("from_url_segment", from_url_segment_method(data)),
+        ("from_numeric", from_numeric_method(data)),
+        ("from_name", from_name_method(data)),
+        ("with_exponent", with_exponent_method(data)),
+        ("symbol_for_script", symbol_for_script_method(data)),
+        ("exponent", exponent_method(data)),
+        ("subunit_fraction", subunit_fraction_method(data)),
+        ("is_fund", is_fund_method(data)),
+        ("is_special", is_special_method(data)),
+        ("is_superseded", is_superseded_method(data)),
+        ("superseded_on", superseded_on_method(data)),
+        ("introduced", introduced_method(data)),
+        ("blurb", blurb_method(data)),
+        ("kind", kind_method(data)),
+        ("latest", latest_method(data)),
+        ("latest_with_factor", latest_with_factor_method(data)),
+        ("flags", flags_method(data)),
+        ("has_flag", has_flag_method()),
+        ("from_country", from_country_method(country_map)),
+    ];
+    if env::var_os("CARGO_FEATURE_NAME_ALIASES").is_some() {
+        methods.push(("name_aliases", name_aliases(data)));
+    }
 
-    let outline = quote! (
-      impl Currency {
-          #numeric_method
+    let mut files: Vec<(String, Vec<u8>)> = methods
+        .into_iter()
+        .map(|(name, method)| {
+            // the fallback arms become unreachable (or the only arm) when
+            // ISO_CURRENCY_COUNTRIES filters out currencies
+            let outline = quote!(
+                #[allow(unreachable_patterns, clippy::match_single_binding)]
+                impl Currency {
+                    #method
+                }
+            );
+            (
+                format!("impl_{}.rs", name),
+                outline.to_string().into_bytes(),
+            )
+        })
+        .collect();
 
-          #name_method
+    if let Some(arena) = arena {
+        let text = arena.text;
+        files.push((
+            "string_arena.rs".to_owned(),
+            quote!(static STRING_ARENA: &str = #text;)
+                .to_string()
+                .into_bytes(),
+        ));
+    }
+    files
+}
 
-          #code_method
+fn write_default(file: &mut impl Write, data: &[IsoData]) {
+    let code = env::var("ISO_CURRENCY_DEFAULT").unwrap_or_else(|_| "XXX".into());
+    if !data.iter().any(|c| c.alpha3 == code) {
+        panic!(
+            "Unknown default currency {} in ISO_CURRENCY_DEFAULT or left out by ISO_CURRENCY_COUNTRIES",
+            code
+        );
+    }
+    let variant = Ident::new(&code, Span::call_site());
+    let outline = quote!(
+        impl Default for Currency {
+            /// Returns `Currency::XXX` unless overridden with `ISO_CURRENCY_DEFAULT` at build time
+            fn default() -> Self {
+                Currency::#variant
+            }
+        }
+    );
 
-          #used_by_method
+    write!(file, "{}", outline).unwrap();
+}
 
-          #symbol_method
+fn write_timeline(file: &mut impl Write, timeline: &[TimelineData]) {
+    let mut countries: Vec<&str> = timeline.iter().map(|p| p.country.as_str()).collect();
+    countries.dedup();
 
-          #from_code_method
+    let match_arms: TokenStream = countries
+        .iter()
+        .map(|country| {
+            let periods: TokenStream = timeline
+                .iter()
+                .filter(|p| p.country == *country)
+                .map(|period| {
+                    let currency = Ident::new(&period.currency, Span::call_site());
+                    let start = date_tokens(period.start);
+                    let end = match period.end {
+                        Some(end) => {
+                            let end = date_tokens(end);
+                            quote!(Some(#end))
+                        }
+                        None => quote!(None),
+                    };
+                    quote! {
+                        CurrencyPeriod { start: #start, end: #end, currency: Currency::#currency },
+                    }
+                })
+                .collect();
+            let country = Ident::new(country, Span::call_site());
+            quote! {
+                Country::#country => &[#periods],
+            }
+        })
+        .collect();
+    let mut currencies: Vec<&str> = timeline.iter().map(|p| p.currency.as_str()).collect();
+    currencies.sort();
+    currencies.dedup();
+    let currency_arms: TokenStream = currencies
+        .iter()
+        .map(|currency| {
+            let mut countries: Vec<&str> = timeline
+                .iter()
+                .filter(|p| p.currency == *currency)
+                .map(|p| p.country.as_str())
+                .collect();
+            countries.dedup();
+            let countries = countries
+                .iter()
+                .map(|country| Ident::new(country, Span::call_site()));
+            let currency = Ident::new(currency, Span::call_site());
+            quote! {
+                Currency::#currency => &[#(Country::#countries),*],
+            }
+        })
+        .collect();
+    let outline = quote!(
+        #[allow(clippy::match_single_binding)]
+        fn country_timeline(country: Country) -> &'static [CurrencyPeriod] {
+            match country {
+                #match_arms
+                _ => &[],
+            }
+        }
+
+        #[allow(clippy::match_single_binding)]
+        fn timeline_countries(currency: Currency) -> &'static [Country] {
+            match currency {
+                #currency_arms
+                _ => &[],
+            }
+        }
+    );
 
-          #from_numeric_method
+    write!(file, "{}", outline).unwrap();
+}
 
-          #exponent_method
+fn write_accepted(file: &mut impl Write, accepted: &[(String, String)]) {
+    let mut countries: Vec<&str> = accepted.iter().map(|(c, _)| c.as_str()).collect();
+    countries.dedup();
 
-          #subunit_fraction_method
+    let match_arms: TokenStream = countries
+        .iter()
+        .map(|country| {
+            let currencies: TokenStream = accepted
+                .iter()
+                .filter(|(c, _)| c == country)
+                .map(|(_, currency)| {
+                    let currency = Ident::new(currency, Span::call_site());
+                    quote!(Currency::#currency,)
+                })
+                .collect();
+            let country = Ident::new(country, Span::call_site());
+            quote! {
+                Country::#country => &[#currencies],
+            }
+        })
+        .collect();
+    let outline = quote!(
+        #[allow(clippy::match_single_binding)]
+        fn country_accepted(country: Country) -> &'static [Currency] {
+            match country {
+                #match_arms
+                _ => &[],
+            }
+        }
+    );
+
+    write!(file, "{}", outline).unwrap();
+}
+
+fn write_symbol_collisions(file: &mut impl Write, data: &[IsoData]) {
+    let mut symbols: Vec<&str> = data.iter().map(|c| c.symbol.as_str()).collect();
+    symbols.sort();
+    symbols.dedup();
+
+    let collisions: TokenStream = symbols
+        .iter()
+        .filter_map(|symbol| {
+            let currencies: Vec<_> = data
+                .iter()
+                .filter(|c| c.symbol == *symbol)
+                .map(|c| Ident::new(&c.alpha3, Span::call_site()))
+                .collect();
+            match currencies.len() > 1 {
+                true => Some(quote! {
+                    (
+                        CurrencySymbol { symbol: #symbol, subunit_symbol: None },
+                        &[#(Currency::#currencies),*],
+                    ),
+                }),
+                false => None,
+            }
+        })
+        .collect();
+    let outline = quote!(
+        // every distinct main symbol, sorted for binary search
+        static SYMBOLS: &[&str] = &[#(#symbols),*];
+
+        static SYMBOL_COLLISIONS: &[(CurrencySymbol, &[Currency])] = &[#collisions];
+
+        /// Returns all symbols which are shared by more than one currency
+        ///
+        /// Each symbol is listed together with the currencies using it, which
+        /// makes it easy to decide which currencies need a disambiguated display
+        /// (e.g. `US$` instead of `$`). Only the main symbol is compared, so the
+        /// returned symbols don't carry a subunit symbol. The list is sorted by
+        /// symbol and includes the generic currency sign `¤` which is used for
+        /// currencies without a symbol of their own.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{symbol_collisions, Currency};
+        ///
+        /// let (_, currencies) = symbol_collisions()
+        ///     .iter()
+        ///     .find(|(symbol, _)| symbol.symbol == "¥")
+        ///     .unwrap();
+        /// assert_eq!(*currencies, &[Currency::CNY, Currency::JPY]);
+        /// ```
+        pub fn symbol_collisions() -> &'static [(CurrencySymbol, &'static [Currency])] {
+            SYMBOL_COLLISIONS
+        }
+    );
 
-          #is_fund_method
+    write!(file, "{}", outline).unwrap();
+}
 
-          #is_special_method
+// lowercased codes and every word-suffix of the names, e.g. "dollar" for
+// "United States dollar", sorted for range scans by prefix
+fn write_prefix_index(file: &mut impl Write, data: &[IsoData]) {
+    let mut keys: Vec<(String, &str)> = Vec::new();
+    for currency in data {
+        keys.push((currency.alpha3.to_lowercase(), &currency.alpha3));
+        let name = currency.name.to_lowercase();
+        let word_starts = name
+            .char_indices()
+            .filter(|(i, _)| *i == 0 || name[..*i].ends_with(' '))
+            .map(|(i, _)| i);
+        for start in word_starts {
+            keys.push((name[start..].to_owned(), &currency.alpha3));
+        }
+    }
+    keys.sort();
+    keys.dedup();
 
-          #is_superseded_method
+    let entries: TokenStream = keys
+        .iter()
+        .map(|(key, code)| {
+            let variant = Ident::new(code, Span::call_site());
+            quote!((#key, Currency::#variant),)
+        })
+        .collect();
+    let outline = quote!(
+        static PREFIX_INDEX: &[(&str, Currency)] = &[#entries];
+    );
 
-          #latest_method
+    write!(file, "{}", outline).unwrap();
+}
 
-          #flags_method
+fn write_multi_currency_countries(
+    file: &mut impl Write,
+    data: &[IsoData],
+    country_map: &HashMap<String, Vec<String>>,
+) {
+    let mut countries: Vec<&String> = country_map.keys().collect();
+    countries.sort();
 
-          #has_flag_method
+    let entries: TokenStream = countries
+        .iter()
+        .filter_map(|country| {
+            let currencies: Vec<_> = country_map[*country]
+                .iter()
+                .filter_map(|code| data.iter().find(|c| &c.alpha3 == code))
+                .filter(|c| !c.is_fund && !c.is_special && c.is_superseded.is_none())
+                .map(|c| Ident::new(&c.alpha3, Span::call_site()))
+                .collect();
+            if currencies.len() < 2 {
+                return None;
+            }
+            let country = Ident::new(country, Span::call_site());
+            Some(quote! {
+                (Country::#country, &[#(Currency::#currencies),*]),
+            })
+        })
+        .collect();
+    let outline = quote!(
+        static MULTI_CURRENCY_COUNTRIES: &[(Country, &[Currency])] = &[#entries];
 
-          #from_country_method
-      }
+        /// Returns all countries with more than one circulating currency
+        ///
+        /// Funds, special and superseded currencies aren't counted. Each
+        /// country is listed together with its currencies and the list is
+        /// sorted by country code.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use iso_currency::{multi_currency_countries, Country, Currency};
+        ///
+        /// let (_, currencies) = multi_currency_countries()
+        ///     .iter()
+        ///     .find(|(country, _)| *country == Country::PA)
+        ///     .unwrap();
+        /// assert_eq!(*currencies, &[Currency::PAB, Currency::USD]);
+        /// ```
+        pub fn multi_currency_countries() -> &'static [(Country, &'static [Currency])] {
+            MULTI_CURRENCY_COUNTRIES
+        }
     );
 
     write!(file, "{}", outline).unwrap();
 }
 
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_option<T>(value: Option<T>, f: impl Fn(T) -> String) -> String {
+    value.map(f).unwrap_or_else(|| "null".to_string())
+}
+
+// the full dataset as a compact JSON array, one object per currency
+fn catalog_json(data: &[IsoData]) -> String {
+    let records: Vec<String> = data
+        .iter()
+        .map(|c| {
+            let used_by: Vec<String> = c
+                .used_by
+                .iter()
+                .flatten()
+                .map(|country| json_string(country))
+                .collect();
+            let issued_by: Vec<String> = c
+                .issued_by
+                .iter()
+                .flatten()
+                .map(|country| json_string(country))
+                .collect();
+            let script_symbols: Vec<String> = c
+                .script_symbols
+                .iter()
+                .map(|(script, symbol)| format!("{}:{}", json_string(script), json_string(symbol)))
+                .collect();
+            format!(
+                "{{\"code\":{},\"numeric\":{},\"name\":{},\"symbol\":{},\"subunit_symbol\":{},\"exponent\":{},\"used_by\":[{}],\"issued_by\":[{}],\"is_fund\":{},\"is_special\":{},\"superseded_by\":{},\"superseded_ratio\":{},\"superseded_on\":{},\"introduced\":{},\"script_symbols\":{{{}}},\"blurb\":{}}}",
+                json_string(&c.alpha3),
+                c.numeric,
+                json_string(&c.name),
+                json_string(&c.symbol),
+                json_option(c.subunit_symbol.as_deref(), json_string),
+                json_option(c.exponent, |e| e.to_string()),
+                used_by.join(","),
+                issued_by.join(","),
+                c.is_fund,
+                c.is_special,
+                json_option(c.is_superseded.as_deref(), json_string),
+                json_option(c.superseded_ratio, |(n, d)| json_string(&format!("{}/{}", n, d))),
+                json_option(c.superseded_on, |date| json_string(&format_date(date))),
+                json_option(c.introduced, |date| json_string(&format_date(date))),
+                script_symbols.join(","),
+                json_option(c.blurb.as_deref(), json_string),
+            )
+        })
+        .collect();
+    format!("[{}]", records.join(","))
+}
+
+// the compiled currencies in the same layout as the source table
+fn min_tsv(data: &[IsoData]) -> String {
+    let mut tsv = String::from(
+        "alpha3\tnumeric\tname\tused_by_alpha2\tsymbol\tsubunit_symbol\texponent\tflags\tsuperseded_ratio\tsuperseded_on\tintroduced\tscript_symbols\tissuing_alpha2\tblurb\n",
+    );
+    for c in data {
+        let mut flags = Vec::new();
+        if c.is_special {
+            flags.push("special".to_string());
+        }
+        if c.is_fund {
+            flags.push("fund".to_string());
+        }
+        if let Some(superseded) = &c.is_superseded {
+            flags.push(format!("superseded({})", superseded));
+        }
+        tsv.push_str(&format!(
+            "{}\t{:03}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            c.alpha3,
+            c.numeric,
+            c.name,
+            c.used_by.as_deref().unwrap_or_default().join(";"),
+            c.symbol,
+            c.subunit_symbol.as_deref().unwrap_or_default(),
+            c.exponent.map(|e| e.to_string()).unwrap_or_default(),
+            flags.join(","),
+            c.superseded_ratio
+                .map(|(n, d)| format!("{}/{}", n, d))
+                .unwrap_or_default(),
+            c.superseded_on.map(format_date).unwrap_or_default(),
+            c.introduced.map(format_date).unwrap_or_default(),
+            c.script_symbols
+                .iter()
+                .map(|(script, symbol)| format!("{}:{}", script, symbol))
+                .collect::<Vec<String>>()
+                .join(";"),
+            // only filled in where it differs, like in the source table
+            match c.issued_by == c.used_by {
+                true => String::new(),
+                false => c.issued_by.as_deref().unwrap_or_default().join(";"),
+            },
+            c.blurb.as_deref().unwrap_or_default(),
+        ));
+    }
+    tsv
+}
+
+// 64 bit FNV-1a, stable across platforms and compiler versions
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Sizes of the generated data, exposed by `build_info()` and printed as
+// build warnings when ISO_CURRENCY_BUILD_REPORT is set
+fn write_build_info(
+    out_dir: &str,
+    table_rows: usize,
+    variants: usize,
+    generated_bytes: usize,
+    catalog_bytes: usize,
+) {
+    let mut features: Vec<String> = env::var("CARGO_CFG_FEATURE")
+        .unwrap_or_default()
+        .split(',')
+        .filter(|f| !f.is_empty())
+        .map(String::from)
+        .collect();
+    features.sort();
+
+    if env::var_os("ISO_CURRENCY_BUILD_REPORT").is_some() {
+        println!(
+            "cargo:warning={} of {} currencies generated",
+            variants, table_rows
+        );
+        println!(
+            "cargo:warning={} bytes of generated code, {} bytes of catalog JSON",
+            generated_bytes, catalog_bytes
+        );
+        println!(
+            "cargo:warning=features: {}",
+            match features.is_empty() {
+                true => "none".to_owned(),
+                false => features.join(", "),
+            }
+        );
+    }
+
+    let build_info = quote! {
+        const BUILD_INFO: BuildInfo = BuildInfo {
+            table_rows: #table_rows,
+            variants: #variants,
+            generated_bytes: #generated_bytes,
+            catalog_bytes: #catalog_bytes,
+            features: &[#(#features),*],
+        };
+    };
+    write_if_changed(
+        &Path::new(out_dir).join("build_info.rs"),
+        build_info.to_string().as_bytes(),
+    );
+}
+
+fn generate(write: impl FnOnce(&mut Vec<u8>)) -> Vec<u8> {
+    let mut code = Vec::new();
+    write(&mut code);
+    code
+}
+
+// Leaves the file untouched if it already has the content, so its
+// modification time only changes along with the code
+fn write_if_changed(path: &Path, content: &[u8]) {
+    if std::fs::read(path).is_ok_and(|existing| existing == content) {
+        return;
+    }
+    std::fs::write(path, content)
+        .unwrap_or_else(|e| panic!("Couldn't write {}: {}", path.display(), e));
+}
+
+// Keeps only the currencies used by the countries listed in the
+// ISO_CURRENCY_COUNTRIES environment variable, plus the currencies which
+// supersede them so `Currency::latest` keeps working.
+fn filter_countries(isodata: Vec<IsoData>) -> Vec<IsoData> {
+    let countries = match env::var("ISO_CURRENCY_COUNTRIES") {
+        Ok(countries) if !countries.trim().is_empty() => countries,
+        _ => return isodata,
+    };
+    let countries: Vec<&str> = countries.split(',').map(str::trim).collect();
+    for country in countries.iter() {
+        if country.len() != 2 || !country.bytes().all(|b| b.is_ascii_uppercase()) {
+            panic!("Invalid country {:?} in ISO_CURRENCY_COUNTRIES", country);
+        }
+    }
+
+    let mut keep: Vec<&str> = isodata
+        .iter()
+        .filter(|c| {
+            c.used_by
+                .iter()
+                .flatten()
+                .any(|country| countries.contains(&country.as_str()))
+        })
+        .map(|c| c.alpha3.as_str())
+        .collect();
+    let keep_matched = !keep.is_empty();
+    // XXX is the documented `Default`, so it's kept like the successors
+    if isodata.iter().any(|c| c.alpha3 == "XXX") {
+        keep.push("XXX");
+    }
+    let mut i = 0;
+    while i < keep.len() {
+        let successor = isodata
+            .iter()
+            .find(|c| c.alpha3 == keep[i])
+            .and_then(|c| c.is_superseded.as_deref());
+        if let Some(successor) = successor {
+            if !keep.contains(&successor) {
+                keep.push(successor);
+            }
+        }
+        i += 1;
+    }
+    if !keep_matched {
+        panic!("ISO_CURRENCY_COUNTRIES doesn't match any currency");
+    }
+
+    let keep: Vec<String> = keep.into_iter().map(String::from).collect();
+    isodata
+        .into_iter()
+        .filter(|c| keep.contains(&c.alpha3))
+        .collect()
+}
+
 fn build_country_map(isodata: &[IsoData]) -> HashMap<String, Vec<String>> {
     let mut country_map = HashMap::new();
     for currency in isodata.iter() {
@@ -725,16 +2327,102 @@ fn build_country_map(isodata: &[IsoData]) -> HashMap<String, Vec<String>> {
 
 fn main() {
     println!("cargo:rerun-if-changed={TSV_TABLE_PATH}");
-    
-    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("isodata.rs");
+    println!("cargo:rerun-if-changed={TIMELINE_TABLE_PATH}");
+    println!("cargo:rerun-if-changed={ACCEPTED_TABLE_PATH}");
+    println!("cargo:rerun-if-env-changed=ISO_CURRENCY_COUNTRIES");
+    println!("cargo:rerun-if-env-changed=ISO_CURRENCY_DEFAULT");
+    println!("cargo:rerun-if-env-changed=ISO_CURRENCY_BUILD_REPORT");
+    println!("cargo:rerun-if-env-changed=ISO_CURRENCY_LEGACY_IDS");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
 
-    let isodata = read_table();
+    let mut isodata = read_table();
+    let mut timeline = read_timeline(&isodata);
+    let mut accepted = read_accepted(&isodata);
+    check_countries(&mut isodata, &mut timeline, &mut accepted);
+    let table_rows = isodata.len();
+    let legacy_ids = read_legacy_ids(&isodata);
+    let isodata = filter_countries(isodata);
+    timeline.retain(|p| isodata.iter().any(|c| c.alpha3 == p.currency));
+    accepted.retain(|(_, currency)| isodata.iter().any(|c| &c.alpha3 == currency));
     let country_map = build_country_map(&isodata);
 
-    {
-        let mut file =
-            BufWriter::new(File::create(out_path).expect("Couldn't write to output file"));
-        write_enum(&mut file, &isodata);
-        write_enum_impl(&mut file, &isodata, &country_map);
+    // every part of the generated code goes into its own file, so it's
+    // easier to inspect and only the changed parts are rewritten
+    let impl_default = env::var_os("CARGO_FEATURE_IMPL_DEFAULT").is_some();
+    let mut files: Vec<(String, Vec<u8>)> =
+        vec![("enum.rs".to_owned(), generate(|f| write_enum(f, &isodata)))];
+    files.extend(enum_impl_files(&isodata, &country_map));
+    let parts: Vec<(&str, Vec<u8>)> = vec![
+        (
+            "default.rs",
+            generate(|f| {
+                if impl_default {
+                    write_default(f, &isodata)
+                }
+            }),
+        ),
+        ("timeline.rs", generate(|f| write_timeline(f, &timeline))),
+        ("accepted.rs", generate(|f| write_accepted(f, &accepted))),
+        (
+            "symbol_collisions.rs",
+            generate(|f| write_symbol_collisions(f, &isodata)),
+        ),
+        (
+            "multi_currency_countries.rs",
+            generate(|f| write_multi_currency_countries(f, &isodata, &country_map)),
+        ),
+        (
+            "prefix_index.rs",
+            generate(|f| write_prefix_index(f, &isodata)),
+        ),
+        (
+            "legacy_ids.rs",
+            generate(|f| write_legacy_ids(f, &isodata, &legacy_ids)),
+        ),
+    ];
+    files.extend(
+        parts
+            .into_iter()
+            .map(|(name, code)| (name.to_owned(), code)),
+    );
+    let generated_dir = Path::new(&out_dir).join("isodata");
+    std::fs::create_dir_all(&generated_dir).expect("Couldn't create generated code directory");
+    let mut includes = String::new();
+    for (name, code) in files.iter() {
+        write_if_changed(&generated_dir.join(name.as_str()), code);
+        includes.push_str(&format!(
+            "include!(concat!(env!(\"OUT_DIR\"), \"/isodata/{}\"));\n",
+            name
+        ));
+    }
+    write_if_changed(&Path::new(&out_dir).join("isodata.rs"), includes.as_bytes());
+    let generated_bytes = files.iter().map(|(_, code)| code.len()).sum();
+
+    let catalog = catalog_json(&isodata);
+    println!(
+        "cargo:rustc-env=ISO_CURRENCY_CATALOG_ETAG=\"{:016x}\"",
+        fnv1a64(catalog.as_bytes())
+    );
+    if env::var_os("CARGO_FEATURE_DATA_ARTIFACTS").is_some() {
+        write_if_changed(
+            &Path::new(&out_dir).join("isodata.json"),
+            catalog.as_bytes(),
+        );
+        write_if_changed(
+            &Path::new(&out_dir).join("isodata.min.tsv"),
+            min_tsv(&isodata).as_bytes(),
+        );
     }
+    write_build_info(
+        &out_dir,
+        table_rows,
+        isodata.len(),
+        generated_bytes,
+        catalog.len(),
+    );
+    write_if_changed(
+        &Path::new(&out_dir).join("catalog.json"),
+        catalog.as_bytes(),
+    );
 }
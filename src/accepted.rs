@@ -0,0 +1,38 @@
+//! Currencies accepted in a country without being official
+
+use crate::{country_accepted, Country, Currency};
+
+impl Currency {
+    /// Returns the currencies commonly accepted in a country besides its official ones
+    ///
+    /// The data is taken from the `accepted.tsv` table and describes de facto
+    /// usage, e.g. euros in Switzerland. It never includes the currencies
+    /// returned by `Currency::from_country`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Country, Currency};
+    ///
+    /// assert_eq!(Currency::accepted_in(Country::CH), vec![Currency::EUR]);
+    /// assert_eq!(Currency::accepted_in(Country::DE), vec![]);
+    /// ```
+    pub fn accepted_in(country: Country) -> Vec<Currency> {
+        country_accepted(country).to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Country, Currency};
+
+    #[test]
+    fn accepted_in_is_not_official() {
+        for country in [Country::CH, Country::LB, Country::NP, Country::VE] {
+            let accepted = Currency::accepted_in(country);
+            assert!(!accepted.is_empty());
+            let official = Currency::from_country(country);
+            assert!(accepted.iter().all(|c| !official.contains(c)));
+        }
+    }
+}
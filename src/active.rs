@@ -0,0 +1,89 @@
+//! Restricting input to currencies which are in circulation
+
+use crate::Currency;
+
+/// Error returned by `Currency::from_code_active`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ActiveCurrencyError {
+    /// The code isn't a known currency code
+    #[error("not a valid ISO 4217 currency code")]
+    Unknown,
+    /// The currency has been replaced by another currency
+    #[error("{} was replaced by {}", .currency.code(), .replacement.code())]
+    Superseded {
+        currency: Currency,
+        replacement: Currency,
+    },
+    /// The currency isn't circulating, e.g. a precious metal or the SDR
+    #[error("{} is not a circulating currency", .0.code())]
+    Special(Currency),
+}
+
+impl Currency {
+    /// Parses a currency code, rejecting superseded and special currencies
+    ///
+    /// This is `Currency::from_code` for validating input which only accepts
+    /// currencies in circulation. The error of a superseded currency names
+    /// its replacement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{ActiveCurrencyError, Currency};
+    ///
+    /// assert_eq!(Currency::from_code_active("EUR"), Ok(Currency::EUR));
+    /// assert_eq!(
+    ///     Currency::from_code_active("HRK"),
+    ///     Err(ActiveCurrencyError::Superseded {
+    ///         currency: Currency::HRK,
+    ///         replacement: Currency::EUR
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Currency::from_code_active("HRK").unwrap_err().to_string(),
+    ///     "HRK was replaced by EUR"
+    /// );
+    /// assert_eq!(
+    ///     Currency::from_code_active("XAU"),
+    ///     Err(ActiveCurrencyError::Special(Currency::XAU))
+    /// );
+    /// ```
+    pub fn from_code_active(code: &str) -> Result<Currency, ActiveCurrencyError> {
+        let currency = Currency::from_code(code).ok_or(ActiveCurrencyError::Unknown)?;
+        if let Some(replacement) = currency.is_superseded() {
+            return Err(ActiveCurrencyError::Superseded {
+                currency,
+                replacement,
+            });
+        }
+        if currency.is_special() {
+            return Err(ActiveCurrencyError::Special(currency));
+        }
+        Ok(currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ActiveCurrencyError, Currency};
+
+    #[test]
+    fn from_code_active() {
+        assert_eq!(Currency::from_code_active("CHE"), Ok(Currency::CHE));
+        assert_eq!(
+            Currency::from_code_active("AAA"),
+            Err(ActiveCurrencyError::Unknown)
+        );
+        assert_eq!(
+            Currency::from_code_active("XDR").unwrap_err().to_string(),
+            "XDR is not a circulating currency"
+        );
+        for currency in Currency::VARIANTS {
+            let active = Currency::from_code_active(currency.code()).is_ok();
+            assert_eq!(
+                active,
+                currency.is_superseded().is_none() && !currency.is_special()
+            );
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! Overflow-aware arithmetic on amounts expressed in minor units
+//!
+//! Plain `i64` arithmetic on cent amounts wraps silently in release builds.
+//! The helpers here widen to `i128` internally and report overflow as an
+//! [`ArithmeticError`] instead.
+
+use std::convert::TryFrom;
+
+use crate::Currency;
+
+/// Error returned by the minor unit arithmetic helpers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ArithmeticError {
+    /// The result doesn't fit into the target integer type
+    #[error("arithmetic overflow")]
+    Overflow,
+    /// The operation would divide by zero
+    #[error("division by zero")]
+    DivisionByZero,
+}
+
+/// An exact conversion factor between two amounts
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Ratio;
+///
+/// let ratio = Ratio::new(1, 1000);
+/// assert_eq!(ratio.to_string(), "1/1000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Ratio {
+    /// Creates a new ratio, the denominator is expected to be non-zero
+    pub const fn new(numerator: u64, denominator: u64) -> Self {
+        Ratio {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Divides rounding half away from zero, `denominator` must not be zero
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    // compared without doubling the remainder, which could overflow
+    if remainder.abs() >= denominator.abs() - remainder.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+// `amount / denominator` rescaled from `from` to `to` decimal places with a
+// single rounding, half away from zero
+fn rescale_round(
+    amount: i128,
+    from: u16,
+    to: u16,
+    denominator: i128,
+) -> Result<i128, ArithmeticError> {
+    if to >= from {
+        let factor = 10_i128
+            .checked_pow(u32::from(to - from))
+            .ok_or(ArithmeticError::Overflow)?;
+        let scaled = amount
+            .checked_mul(factor)
+            .ok_or(ArithmeticError::Overflow)?;
+        Ok(div_round(scaled, denominator))
+    } else {
+        // a divisor too large for i128 rounds every i128 amount to zero
+        match 10_i128
+            .checked_pow(u32::from(from - to))
+            .and_then(|factor| factor.checked_mul(denominator))
+        {
+            Some(divisor) => Ok(div_round(amount, divisor)),
+            None => Ok(0),
+        }
+    }
+}
+
+fn narrow(value: i128) -> Result<i64, ArithmeticError> {
+    i64::try_from(value).map_err(|_| ArithmeticError::Overflow)
+}
+
+impl Currency {
+    /// Adds `other`, given with `other_exponent` decimal places, to `amount` in the currency's minor units
+    ///
+    /// `other` is first rescaled to the currency's exponent, rounding half
+    /// away from zero, e.g. to add a fee computed with 4 decimal places to a
+    /// cent amount. Currencies without a minor unit are treated as having an
+    /// exponent of 0. Returns `ArithmeticError::Overflow` instead of wrapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{ArithmeticError, Currency};
+    ///
+    /// // 10.50 EUR + 2.50 EUR
+    /// assert_eq!(Currency::EUR.checked_add_minor(1050, 250, 2), Ok(1300));
+    /// // 10.50 EUR + 0.1275 EUR
+    /// assert_eq!(Currency::EUR.checked_add_minor(1050, 1275, 4), Ok(1063));
+    /// // 100 JPY + 1.50 JPY
+    /// assert_eq!(Currency::JPY.checked_add_minor(100, 150, 2), Ok(102));
+    /// assert_eq!(Currency::EUR.checked_add_minor(i64::MAX, 1, 2), Err(ArithmeticError::Overflow));
+    /// ```
+    pub fn checked_add_minor(
+        self,
+        amount: i64,
+        other: i64,
+        other_exponent: u16,
+    ) -> Result<i64, ArithmeticError> {
+        let exponent = self.exponent().unwrap_or(0);
+        let other = rescale_round(i128::from(other), other_exponent, exponent, 1)?;
+        narrow(i128::from(amount) + other)
+    }
+
+    /// Rescales an amount in the currency's minor units to `exponent` decimal places
+    ///
+    /// Increasing the precision saturates at the bounds of `i64`, reducing it
+    /// rounds half away from zero. Currencies without a minor unit are treated
+    /// as having an exponent of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// // 12.34 EUR with 4 decimal places
+    /// assert_eq!(Currency::EUR.saturating_scale(1234, 4), 123400);
+    /// // 12.345 KWD rounded to 2 decimal places
+    /// assert_eq!(Currency::KWD.saturating_scale(12345, 2), 1235);
+    /// assert_eq!(Currency::JPY.saturating_scale(i64::MAX, 2), i64::MAX);
+    /// ```
+    pub fn saturating_scale(self, amount: i64, exponent: u16) -> i64 {
+        let current = self.exponent().unwrap_or(0);
+        let amount = i128::from(amount);
+        let scaled = if exponent >= current {
+            match 10_i128.checked_pow(u32::from(exponent - current)) {
+                Some(factor) => amount.saturating_mul(factor),
+                None if amount == 0 => 0,
+                None => amount.signum() * i128::MAX,
+            }
+        } else {
+            match 10_i128.checked_pow(u32::from(current - exponent)) {
+                Some(factor) => div_round(amount, factor),
+                None => 0,
+            }
+        };
+        scaled.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64
+    }
+
+    /// Computes `amount * numerator / denominator` in the currency's minor units
+    ///
+    /// `amount` is given with `precision` decimal places, e.g. a unit price
+    /// with 4 decimal places, and the result is rounded half away from zero
+    /// to a whole minor unit of the currency. The computation is exact up to
+    /// that single rounding. Currencies without a minor unit are treated as
+    /// having an exponent of 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{ArithmeticError, Currency};
+    ///
+    /// // a third of 10.00 EUR
+    /// assert_eq!(Currency::EUR.mul_div_round(1000, 2, 1, 3), Ok(333));
+    /// assert_eq!(Currency::EUR.mul_div_round(1000, 2, 2, 3), Ok(667));
+    /// // 3 items at 0.3333 EUR
+    /// assert_eq!(Currency::EUR.mul_div_round(3333, 4, 3, 1), Ok(100));
+    /// // 3 items at 0.5 KWD
+    /// assert_eq!(Currency::KWD.mul_div_round(5, 1, 3, 1), Ok(1500));
+    /// assert_eq!(Currency::EUR.mul_div_round(1000, 2, 1, 0), Err(ArithmeticError::DivisionByZero));
+    /// ```
+    pub fn mul_div_round(
+        self,
+        amount: i64,
+        precision: u16,
+        numerator: i64,
+        denominator: i64,
+    ) -> Result<i64, ArithmeticError> {
+        if denominator == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let product = i128::from(amount) * i128::from(numerator);
+        let exponent = self.exponent().unwrap_or(0);
+        narrow(rescale_round(
+            product,
+            precision,
+            exponent,
+            i128::from(denominator),
+        )?)
+    }
+
+    /// Splits an amount in the currency's minor units proportionally to `weights`
+    ///
+    /// Every share is rounded towards zero to a whole minor unit and the
+    /// minor units left over are handed out one each to the shares with the
+    /// largest remainders (the largest remainder method), earlier shares
+    /// first on ties. The shares always add up to `total_minor` exactly.
+    ///
+    /// Returns `ArithmeticError::DivisionByZero` if all weights are zero or
+    /// there are none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{ArithmeticError, Currency};
+    ///
+    /// // 100.00 EUR over three equal invoice lines
+    /// assert_eq!(Currency::EUR.allocate_by_weights(10000, &[1, 1, 1]), Ok(vec![3334, 3333, 3333]));
+    /// assert_eq!(Currency::JPY.allocate_by_weights(-10, &[3, 7]), Ok(vec![-3, -7]));
+    /// assert_eq!(Currency::EUR.allocate_by_weights(100, &[0, 0]), Err(ArithmeticError::DivisionByZero));
+    /// ```
+    pub fn allocate_by_weights(
+        self,
+        total_minor: i128,
+        weights: &[u32],
+    ) -> Result<Vec<i128>, ArithmeticError> {
+        let sum: u128 = weights.iter().map(|w| u128::from(*w)).sum();
+        if sum == 0 {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        let magnitude = total_minor.unsigned_abs();
+
+        let mut shares = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        for (i, weight) in weights.iter().enumerate() {
+            let product = magnitude
+                .checked_mul(u128::from(*weight))
+                .ok_or(ArithmeticError::Overflow)?;
+            shares.push(product / sum);
+            remainders.push((product % sum, i));
+        }
+        let allocated: u128 = shares.iter().sum();
+        // stable, so equal remainders keep the order of the weights
+        remainders.sort_by_key(|r| std::cmp::Reverse(r.0));
+        for (_, i) in remainders.iter().take((magnitude - allocated) as usize) {
+            shares[*i] += 1;
+        }
+
+        Ok(shares
+            .into_iter()
+            // a share is at most the magnitude, so only i128::MIN doesn't fit
+            .map(|share| match total_minor < 0 {
+                true => (share as i128).wrapping_neg(),
+                false => share as i128,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArithmeticError, Currency};
+
+    #[test]
+    fn checked_add_minor() {
+        assert_eq!(Currency::USD.checked_add_minor(-500, 200, 2), Ok(-300));
+        assert_eq!(Currency::USD.checked_add_minor(-500, -25, 3), Ok(-503));
+        assert_eq!(Currency::KWD.checked_add_minor(1000, 5, 2), Ok(1050));
+        assert_eq!(Currency::XAU.checked_add_minor(1, 49, 2), Ok(1));
+        assert_eq!(Currency::EUR.checked_add_minor(0, 1, u16::MAX), Ok(0));
+        assert_eq!(
+            Currency::USD.checked_add_minor(i64::MIN, -1, 2),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(Currency::USD.checked_add_minor(0, 1, 0), Ok(100));
+        assert_eq!(
+            Currency::USD.checked_add_minor(0, i64::MAX, 0),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn saturating_scale() {
+        assert_eq!(Currency::EUR.saturating_scale(1234, 2), 1234);
+        assert_eq!(Currency::EUR.saturating_scale(1250, 0), 13);
+        assert_eq!(Currency::EUR.saturating_scale(-1250, 0), -13);
+        assert_eq!(Currency::EUR.saturating_scale(1249, 0), 12);
+        assert_eq!(Currency::XAU.saturating_scale(3, 2), 300);
+        assert_eq!(Currency::EUR.saturating_scale(i64::MIN, 4), i64::MIN);
+        assert_eq!(Currency::EUR.saturating_scale(5, u16::MAX), i64::MAX);
+        assert_eq!(Currency::CLF.saturating_scale(5, 0), 0);
+    }
+
+    #[test]
+    fn mul_div_round() {
+        assert_eq!(Currency::EUR.mul_div_round(-1000, 2, 1, 3), Ok(-333));
+        assert_eq!(Currency::EUR.mul_div_round(-1000, 2, 2, 3), Ok(-667));
+        assert_eq!(Currency::EUR.mul_div_round(5, 2, 1, 2), Ok(3));
+        assert_eq!(Currency::EUR.mul_div_round(5, 2, 1, -2), Ok(-3));
+        // rounded once, not at the input precision and again after dividing
+        assert_eq!(Currency::EUR.mul_div_round(14999, 4, 1, 3), Ok(50));
+        assert_eq!(Currency::JPY.mul_div_round(12345, 2, 1, 1), Ok(123));
+        assert_eq!(Currency::EUR.mul_div_round(7, 0, 1, 2), Ok(350));
+        assert_eq!(
+            Currency::EUR.mul_div_round(i64::MAX, 2, i64::MAX, i64::MAX),
+            Ok(i64::MAX)
+        );
+        assert_eq!(
+            Currency::EUR.mul_div_round(i64::MAX, 2, 2, 1),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            Currency::EUR.mul_div_round(i64::MAX, 0, i64::MAX, 1),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(Currency::EUR.mul_div_round(i64::MAX, 60, 1, 1), Ok(0));
+        // a remainder of 2^126 mustn't overflow when rounding
+        assert_eq!(
+            Currency::JPY.mul_div_round(i64::MIN, 38, i64::MIN, 1),
+            Ok(1)
+        );
+        assert_eq!(
+            Currency::JPY.mul_div_round(i64::MIN, 38, i64::MAX, 1),
+            Ok(-1)
+        );
+    }
+
+    #[test]
+    fn allocate_by_weights() {
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(100, &[1, 1, 1, 1, 1, 1]),
+            Ok(vec![17, 17, 17, 17, 16, 16])
+        );
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(-5, &[2, 0, 1]),
+            Ok(vec![-3, 0, -2])
+        );
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(7, &[0, 5]),
+            Ok(vec![0, 7])
+        );
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(i128::MIN, &[1]),
+            Ok(vec![i128::MIN])
+        );
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(i128::MAX, &[u32::MAX, 1]),
+            Err(ArithmeticError::Overflow)
+        );
+        assert_eq!(
+            Currency::EUR.allocate_by_weights(1, &[]),
+            Err(ArithmeticError::DivisionByZero)
+        );
+
+        let shares = Currency::KWD
+            .allocate_by_weights(1_000_003, &[13, 29, 31, 7])
+            .unwrap();
+        assert_eq!(shares.iter().sum::<i128>(), 1_000_003);
+    }
+}
@@ -0,0 +1,55 @@
+//! Paths of the data files emitted at build time
+//!
+//! With the `data-artifacts` feature the build script writes the exact
+//! dataset compiled into the crate to two files, so build steps in other
+//! languages can consume the same data:
+//!
+//! - `isodata.json`, a JSON array with one object per currency in the same
+//!   format as `catalog_json`
+//! - `isodata.min.tsv`, a tab separated table in the same layout as the
+//!   crate's `isodata.tsv`, with normalized symbols and only the compiled
+//!   currencies
+//!
+//! The files live in the crate's `OUT_DIR`. To pass their location on to a
+//! non-Rust build step, add `iso_currency` as a build dependency with the
+//! `data-artifacts` feature and read the constants from your build script:
+//!
+//! ```no_run
+//! // build.rs
+//! let json = std::fs::read_to_string(iso_currency::artifacts::JSON_PATH).unwrap();
+//! println!("cargo:rustc-env=CURRENCY_DATA={}", iso_currency::artifacts::JSON_PATH);
+//! ```
+
+/// Absolute path of the `isodata.json` artifact
+pub const JSON_PATH: &str = concat!(env!("OUT_DIR"), "/isodata.json");
+
+/// Absolute path of the `isodata.min.tsv` artifact
+pub const TSV_PATH: &str = concat!(env!("OUT_DIR"), "/isodata.min.tsv");
+
+#[cfg(test)]
+mod tests {
+    use super::{JSON_PATH, TSV_PATH};
+
+    #[test]
+    fn artifacts_exist() {
+        let json = std::fs::read_to_string(JSON_PATH).unwrap();
+        assert!(json.starts_with("[{\"code\":\"AED\""));
+
+        let tsv = std::fs::read_to_string(TSV_PATH).unwrap();
+        let source = std::fs::read_to_string("isodata.tsv").unwrap();
+        assert_eq!(tsv.lines().count(), source.lines().count());
+        assert!(tsv.contains(
+            "\nALL\t008\tAlbanian lek\tAL\tL\tq\t2\t\t\t\t1926-01-01\t\t\tOfficial currency of Albania.\n"
+        ));
+        assert!(tsv.contains(
+            "\nRSD\t941\tSerbian dinar\tRS\tдин\t\t2\t\t\t\t2003-07-01\tCyrl:дин;Latn:din\t\tOfficial currency of Serbia.\n"
+        ));
+        assert!(tsv.contains(
+            "\nVES\t928\tVenezuelan bolívar soberano\tVE\tBs.\t\t2\tsuperseded(VED)\t1/1000000\t2021-10-01\t2018-08-20\t\t\t\n"
+        ));
+        assert!(tsv.contains("\tAT;BE;HR;CY;EE;FI;FR;DE;GR;IE;IT;LV;LT;LU;MT;NL;PT;SK;SI;ES\t"));
+        assert!(tsv.contains(
+            "\tPF;NC;WF\t₣\t\t0\t\t\t\t1945-12-26\t\t\tCurrency of the French overseas collectivities in the Pacific, pegged to the euro.\n"
+        ));
+    }
+}
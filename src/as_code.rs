@@ -0,0 +1,71 @@
+//! A currency wrapper displayed and parsed by its code
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Currency, ParseCurrencyError};
+
+/// A currency which is displayed as its code
+///
+/// `Currency` is displayed as its name but parsed from its code, so
+/// `to_string` and `parse` don't round trip. `AsCode` uses the code for both,
+/// which is what generic code relying on `Display` and `FromStr` expects,
+/// e.g. configuration loaders or default values of command line arguments.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{AsCode, Currency};
+///
+/// let currency = AsCode(Currency::EUR);
+/// assert_eq!(currency.to_string(), "EUR");
+/// assert_eq!("EUR".parse::<AsCode>(), Ok(currency));
+/// assert_eq!(Currency::from(currency), Currency::EUR);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct AsCode(pub Currency);
+
+impl fmt::Display for AsCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0.code())
+    }
+}
+
+impl FromStr for AsCode {
+    type Err = ParseCurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::from_str(s).map(AsCode)
+    }
+}
+
+impl From<Currency> for AsCode {
+    fn from(currency: Currency) -> Self {
+        AsCode(currency)
+    }
+}
+
+impl From<AsCode> for Currency {
+    fn from(currency: AsCode) -> Self {
+        currency.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AsCode, Currency};
+
+    #[test]
+    fn round_trip() {
+        for currency in Currency::VARIANTS {
+            let code = AsCode(*currency);
+            assert_eq!(code.to_string().parse::<AsCode>(), Ok(code));
+        }
+        assert!("Euro".parse::<AsCode>().is_err());
+    }
+}
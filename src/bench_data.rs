@@ -0,0 +1,121 @@
+//! Deterministic mixed-currency workloads for benchmarks
+//!
+//! The generated data is skewed towards the most traded currencies, like
+//! real payment traffic, but also includes currencies with 0 and 3 decimal
+//! places and a precious metal. The same seed always yields the same data so
+//! results can be compared across releases.
+//!
+//! # Example
+//!
+//! ```
+//! use iso_currency::bench_data;
+//!
+//! let currencies = bench_data::currencies(1000, 42);
+//! assert_eq!(currencies.len(), 1000);
+//! assert_eq!(currencies, bench_data::currencies(1000, 42));
+//! ```
+
+use crate::Currency;
+
+// (code, weight) pairs, the weights add up to 100. Codes which are filtered
+// out of the enum with ISO_CURRENCY_COUNTRIES are skipped.
+static WEIGHTS: &[(&str, u64)] = &[
+    ("USD", 40),
+    ("EUR", 25),
+    ("GBP", 8),
+    ("JPY", 6),
+    ("CNY", 5),
+    ("CHF", 3),
+    ("CAD", 3),
+    ("AUD", 3),
+    ("SEK", 2),
+    ("KWD", 1),
+    ("BHD", 1),
+    ("CLP", 1),
+    ("ISK", 1),
+    ("XAU", 1),
+];
+
+// splitmix64, good enough for benchmark inputs and free of dependencies
+fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn pick(state: &mut u64) -> Currency {
+    let available = || {
+        WEIGHTS
+            .iter()
+            .filter_map(|(code, weight)| Some((Currency::from_code(code)?, *weight)))
+    };
+    let total: u64 = available().map(|(_, weight)| weight).sum();
+    let mut roll = next(state) % total.max(1);
+    for (currency, weight) in available() {
+        if roll < weight {
+            return currency;
+        }
+        roll -= weight;
+    }
+    panic!("none of the benchmark currencies are part of the dataset")
+}
+
+/// Returns `len` currencies drawn from a realistic distribution
+pub fn currencies(len: usize, seed: u64) -> Vec<Currency> {
+    let mut state = seed;
+    (0..len).map(|_| pick(&mut state)).collect()
+}
+
+/// Returns `len` currency codes as they arrive from external input
+///
+/// About one in twenty codes isn't a known ISO 4217 code, to exercise the
+/// failure paths of parsing.
+pub fn codes(len: usize, seed: u64) -> Vec<&'static str> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| match next(&mut state) % 20 {
+            0 => "XYZ",
+            _ => pick(&mut state).code(),
+        })
+        .collect()
+}
+
+/// Returns `len` amounts in minor units paired with their currency
+///
+/// Amounts range from 1 to 1 000 000 minor units.
+pub fn amounts(len: usize, seed: u64) -> Vec<(Currency, i64)> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            let currency = pick(&mut state);
+            (currency, (next(&mut state) % 1_000_000) as i64 + 1)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{amounts, codes, currencies, WEIGHTS};
+    use crate::Currency;
+
+    #[test]
+    fn weights_add_up() {
+        assert_eq!(WEIGHTS.iter().map(|(_, w)| w).sum::<u64>(), 100);
+    }
+
+    #[test]
+    fn distribution() {
+        let data = currencies(10_000, 7);
+        let usd = data.iter().filter(|c| **c == Currency::USD).count();
+        assert!((3_500..4_500).contains(&usd));
+        assert_ne!(currencies(100, 1), currencies(100, 2));
+
+        let codes = codes(10_000, 7);
+        assert!(codes.iter().any(|c| Currency::from_code(c).is_none()));
+        assert!(amounts(1000, 7)
+            .iter()
+            .all(|(_, a)| (1..=1_000_000).contains(a)));
+    }
+}
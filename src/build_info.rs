@@ -0,0 +1,57 @@
+//! Information about the data generated when the crate was built
+
+/// Sizes of the generated data and the enabled features
+///
+/// Useful for keeping track of binary size budgets when filtering the
+/// dataset with `ISO_CURRENCY_COUNTRIES` or enabling features. Setting the
+/// `ISO_CURRENCY_BUILD_REPORT` environment variable prints the same
+/// information as warnings while building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BuildInfo {
+    /// The number of currencies in the data table
+    pub table_rows: usize,
+    /// The number of generated `Currency` variants
+    pub variants: usize,
+    /// The size of the generated Rust code in bytes
+    pub generated_bytes: usize,
+    /// The size of the catalog JSON in bytes
+    pub catalog_bytes: usize,
+    /// The enabled crate features, sorted
+    pub features: &'static [&'static str],
+}
+
+include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+
+/// Returns information about the data generated when the crate was built
+///
+/// # Example
+///
+/// ```
+/// let info = iso_currency::build_info();
+/// assert!(info.variants <= info.table_rows);
+/// assert!(info.generated_bytes > 0);
+/// ```
+pub fn build_info() -> BuildInfo {
+    BUILD_INFO
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+
+    #[test]
+    fn build_info() {
+        let info = crate::build_info();
+        assert_eq!(info.variants, Currency::VARIANTS.len());
+        assert_eq!(
+            info.catalog_bytes,
+            include_str!(concat!(env!("OUT_DIR"), "/catalog.json")).len()
+        );
+        assert_eq!(
+            info.features.contains(&"with-serde"),
+            cfg!(feature = "with-serde")
+        );
+        assert!(info.features.windows(2).all(|w| w[0] < w[1]));
+    }
+}
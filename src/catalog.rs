@@ -0,0 +1,198 @@
+//! The full dataset prepared for serving over HTTP
+
+/// Returns a strong HTTP entity tag for the currency dataset
+///
+/// The tag is a hash of the JSON representation of all currencies compiled
+/// into the crate, computed when the crate is built. It only changes when the
+/// data changes and is already quoted, so it can be used as the value of an
+/// `ETag` header as is.
+///
+/// # Example
+///
+/// ```
+/// let etag = iso_currency::catalog_etag();
+/// assert!(etag.starts_with('"') && etag.ends_with('"'));
+/// ```
+pub fn catalog_etag() -> &'static str {
+    env!("ISO_CURRENCY_CATALOG_ETAG")
+}
+
+/// A JSON Schema describing one currency of the dataset
+///
+/// Each object in `catalog_json` (and in the `isodata.json` data artifact)
+/// matches this schema. Data catalog tooling can register the dataset with
+/// it without hand-written metadata.
+///
+/// # Example
+///
+/// ```
+/// let schema: serde_json::Value = serde_json::from_str(iso_currency::SCHEMA_JSON).unwrap();
+/// assert_eq!(schema["properties"]["numeric"]["type"], "integer");
+/// ```
+pub const SCHEMA_JSON: &str = r#"{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "title": "Currency",
+  "description": "An ISO 4217 currency",
+  "type": "object",
+  "properties": {
+    "code": {
+      "description": "The three letter ISO 4217 code",
+      "type": "string",
+      "pattern": "^[A-Z]{3}$"
+    },
+    "numeric": {
+      "description": "The ISO 4217 numeric code",
+      "type": "integer",
+      "minimum": 1,
+      "maximum": 999
+    },
+    "name": {
+      "description": "The English name of the currency",
+      "type": "string"
+    },
+    "symbol": {
+      "description": "The commonly used symbol, the generic currency sign if there is none",
+      "type": "string"
+    },
+    "subunit_symbol": {
+      "description": "The symbol of the minor unit",
+      "type": ["string", "null"]
+    },
+    "exponent": {
+      "description": "The number of decimal places of the minor unit, null for currencies without a minor unit",
+      "type": ["integer", "null"],
+      "minimum": 0
+    },
+    "used_by": {
+      "description": "ISO 3166-1 alpha-2 codes of the countries using the currency",
+      "type": "array",
+      "items": { "type": "string", "pattern": "^[A-Z]{2}$" }
+    },
+    "issued_by": {
+      "description": "ISO 3166-1 alpha-2 codes of the countries issuing the currency",
+      "type": "array",
+      "items": { "type": "string", "pattern": "^[A-Z]{2}$" }
+    },
+    "is_fund": {
+      "description": "Whether the currency is a fund",
+      "type": "boolean"
+    },
+    "is_special": {
+      "description": "Whether the currency is special, e.g. a precious metal or the SDR",
+      "type": "boolean"
+    },
+    "superseded_by": {
+      "description": "The code of the currency which replaced this one",
+      "type": ["string", "null"],
+      "pattern": "^[A-Z]{3}$"
+    },
+    "superseded_ratio": {
+      "description": "The factor converting an amount to the replacing currency, written as numerator/denominator",
+      "type": ["string", "null"],
+      "pattern": "^[0-9]+/[0-9]+$"
+    },
+    "superseded_on": {
+      "description": "The date from which the currency was replaced, as YYYY-MM-DD",
+      "type": ["string", "null"],
+      "format": "date"
+    },
+    "introduced": {
+      "description": "The date on which the currency was introduced, as YYYY-MM-DD",
+      "type": ["string", "null"],
+      "format": "date"
+    },
+    "script_symbols": {
+      "description": "Symbols written in a specific script, keyed by ISO 15924 script code",
+      "type": "object",
+      "additionalProperties": { "type": "string" }
+    },
+    "blurb": {
+      "description": "A one sentence description of the currency, null if there is none",
+      "type": ["string", "null"]
+    }
+  },
+  "required": [
+    "code",
+    "numeric",
+    "name",
+    "symbol",
+    "subunit_symbol",
+    "exponent",
+    "used_by",
+    "issued_by",
+    "is_fund",
+    "is_special",
+    "superseded_by",
+    "superseded_ratio",
+    "superseded_on",
+    "introduced",
+    "script_symbols",
+    "blurb"
+  ],
+  "additionalProperties": false
+}"#;
+
+/// Returns the full dataset serialized as JSON
+///
+/// The JSON is generated when the crate is built, so serving it doesn't
+/// need any serialization at runtime. It is a compact array with one object
+/// per currency, sorted like the `Currency` enum:
+///
+/// ```json
+/// {"code":"EUR","numeric":978,"name":"Euro","symbol":"€","subunit_symbol":null,
+///  "exponent":2,"used_by":["AX","AD"],"issued_by":["AT","BE"],"is_fund":false,
+///  "is_special":false,"superseded_by":null,"superseded_ratio":null,
+///  "superseded_on":null,"introduced":"1999-01-01","script_symbols":{},
+///  "blurb":"Official currency of the eurozone, ..."}
+/// ```
+///
+/// Use `catalog_etag` for the matching entity tag and `SCHEMA_JSON` for a
+/// description of the fields.
+#[cfg(feature = "embed-json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embed-json")))]
+pub fn catalog_json() -> &'static [u8] {
+    include_bytes!(concat!(env!("OUT_DIR"), "/catalog.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn etag_is_quoted_hex() {
+        let etag = crate::catalog_etag();
+        assert_eq!(etag.len(), 18);
+        assert!(etag[1..17].bytes().all(|b| b.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn schema_matches_catalog() {
+        let schema: serde_json::Value = serde_json::from_str(crate::SCHEMA_JSON).unwrap();
+        let mut properties: Vec<&String> =
+            schema["properties"].as_object().unwrap().keys().collect();
+        properties.sort();
+
+        let catalog: Vec<serde_json::Map<String, serde_json::Value>> =
+            serde_json::from_str(include_str!(concat!(env!("OUT_DIR"), "/catalog.json"))).unwrap();
+        for currency in catalog {
+            let mut keys: Vec<&String> = currency.keys().collect();
+            keys.sort();
+            assert_eq!(keys, properties);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "embed-json")]
+    fn catalog_json_is_valid() {
+        let catalog: Vec<serde_json::Value> =
+            serde_json::from_slice(crate::catalog_json()).unwrap();
+        let eur = catalog.iter().find(|c| c["code"] == "EUR").unwrap();
+        assert_eq!(eur["numeric"], 978);
+        assert_eq!(eur["exponent"], 2);
+        assert_eq!(eur["subunit_symbol"], serde_json::Value::Null);
+        let hrk = catalog.iter().find(|c| c["code"] == "HRK").unwrap();
+        assert_eq!(hrk["superseded_by"], "EUR");
+        assert_eq!(hrk["superseded_ratio"], "2000/15069");
+        let xau = catalog.iter().find(|c| c["code"] == "XAU").unwrap();
+        assert_eq!(xau["is_special"], true);
+        assert_eq!(xau["used_by"], serde_json::json!([]));
+    }
+}
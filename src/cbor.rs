@@ -0,0 +1,128 @@
+//! Deterministic CBOR encoding of currencies
+//!
+//! A currency is encoded as its numeric code, an unsigned integer in the
+//! shortest form, wrapped in tag [`TAG`]. As the numeric code is fixed for
+//! every currency the encoding is canonical, which matters when currencies
+//! are part of signed COSE payloads.
+//!
+//! Use the module with `#[serde(with = "iso_currency::cbor")]` on a field
+//! serialized with `ciborium`.
+//!
+//! # Example
+//!
+//! ```
+//! use iso_currency::{cbor, Currency};
+//!
+//! let bytes = cbor::encode(Currency::EUR);
+//! assert_eq!(bytes, [0xd9, 0x10, 0x79, 0x19, 0x03, 0xd2]);
+//! assert_eq!(cbor::decode(&bytes).unwrap(), Currency::EUR);
+//! ```
+
+use ciborium::tag::Required;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+
+use crate::Currency;
+
+/// The CBOR tag wrapping a currency
+///
+/// The tag isn't registered with IANA, 4217 is chosen after the standard.
+pub const TAG: u64 = 4217;
+
+pub fn serialize<S>(currency: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Required::<u16, TAG>(currency.numeric()).serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Required(numeric) = Required::<u16, TAG>::deserialize(deserializer)?;
+    Currency::from_numeric(numeric).ok_or_else(|| {
+        de::Error::invalid_value(
+            de::Unexpected::Unsigned(u64::from(numeric)),
+            &"an ISO 4217 numeric currency code",
+        )
+    })
+}
+
+/// Encodes a currency into its canonical CBOR representation
+pub fn encode(currency: Currency) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(6);
+    ciborium::into_writer(&Required::<u16, TAG>(currency.numeric()), &mut bytes)
+        .expect("writing to a Vec can't fail");
+    bytes
+}
+
+/// Decodes a currency from its CBOR representation
+///
+/// Fails if the tag is missing or different, or if the numeric code is
+/// unknown.
+pub fn decode(bytes: &[u8]) -> Result<Currency, ciborium::de::Error<std::io::Error>> {
+    struct Wrapper(Currency);
+
+    impl<'de> Deserialize<'de> for Wrapper {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize(deserializer).map(Wrapper)
+        }
+    }
+
+    ciborium::from_reader::<Wrapper, _>(bytes).map(|w| w.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use crate::Currency;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payment {
+        amount: u64,
+        #[serde(with = "super")]
+        currency: Currency,
+    }
+
+    #[test]
+    fn canonical() {
+        // numeric codes below 24 fit into the initial byte, below 256 into one more
+        assert_eq!(encode(Currency::ALL), [0xd9, 0x10, 0x79, 0x08]);
+        assert_eq!(encode(Currency::DZD), [0xd9, 0x10, 0x79, 0x0c]);
+        assert_eq!(encode(Currency::BBD), [0xd9, 0x10, 0x79, 0x18, 0x34]);
+        assert_eq!(encode(Currency::XXX), [0xd9, 0x10, 0x79, 0x19, 0x03, 0xe7]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let payment = Payment {
+            amount: 1050,
+            currency: Currency::JPY,
+        };
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&payment, &mut bytes).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<Payment, _>(&bytes[..]).unwrap(),
+            payment
+        );
+
+        let mut again = Vec::new();
+        ciborium::into_writer(&payment, &mut again).unwrap();
+        assert_eq!(bytes, again);
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        // untagged 978
+        assert!(decode(&[0x19, 0x03, 0xd2]).is_err());
+        // tag 4218
+        assert!(decode(&[0xd9, 0x10, 0x7a, 0x19, 0x03, 0xd2]).is_err());
+        // unknown numeric code 1
+        assert!(decode(&[0xd9, 0x10, 0x79, 0x01]).is_err());
+    }
+}
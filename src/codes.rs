@@ -0,0 +1,120 @@
+//! Validation of currency codes independent of the crate's data
+
+use std::ops::RangeInclusive;
+
+use crate::Currency;
+
+impl Currency {
+    /// All numeric codes which are syntactically valid in ISO 4217
+    ///
+    /// Numeric codes are three digits, `000` isn't used.
+    pub const NUMERIC_RANGE: RangeInclusive<u16> = 1..=999;
+
+    /// Numeric codes reserved for assignment by the ISO 4217 maintenance agency
+    ///
+    /// Codes below 900 follow the ISO 3166-1 numeric code of the issuing
+    /// country. The 900 to 999 block isn't tied to any country and is used
+    /// for funds, supranational units, precious metals, the testing and
+    /// "no currency" codes, and for replacement currencies whose country code
+    /// was already taken (e.g. `TRY` is 949).
+    pub const RESERVED_NUMERIC_RANGE: RangeInclusive<u16> = 900..=999;
+
+    /// Returns true if the numeric code lies in the range reserved for the
+    /// maintenance agency
+    ///
+    /// Together with `Currency::NUMERIC_RANGE` and `Currency::from_numeric`
+    /// this allows triaging bad data: a code outside of the numeric range is
+    /// invalid, a code without a currency is unassigned but plausible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert!(Currency::is_reserved_numeric(999));
+    /// assert!(Currency::is_reserved_numeric(901));
+    /// assert!(!Currency::is_reserved_numeric(840));
+    ///
+    /// let triage = |code: u16| match Currency::from_numeric(code) {
+    ///     Some(_) => "assigned",
+    ///     None if Currency::NUMERIC_RANGE.contains(&code) => "unassigned",
+    ///     None => "invalid",
+    /// };
+    /// assert_eq!(triage(978), "assigned");
+    /// assert_eq!(triage(123), "unassigned");
+    /// assert_eq!(triage(1000), "invalid");
+    /// ```
+    pub fn is_reserved_numeric(numeric: u16) -> bool {
+        Self::RESERVED_NUMERIC_RANGE.contains(&numeric)
+    }
+
+    /// Returns true if the code follows the ISO 4217 alphabetic code grammar
+    ///
+    /// A well-formed code is exactly three ASCII uppercase letters. This
+    /// doesn't check whether the code is assigned, so ingestion can tell
+    /// malformed tokens apart from valid but unknown codes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let triage = |code: &str| match Currency::from_code(code) {
+    ///     Some(_) => "known",
+    ///     None if Currency::is_wellformed_code(code) => "unknown",
+    ///     None => "malformed",
+    /// };
+    /// assert_eq!(triage("EUR"), "known");
+    /// assert_eq!(triage("ABC"), "unknown");
+    /// assert_eq!(triage("eur"), "malformed");
+    /// assert_eq!(triage("EURO"), "malformed");
+    /// ```
+    pub fn is_wellformed_code(code: &str) -> bool {
+        code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase())
+    }
+
+    /// Returns true if the code is well-formed and starts with an `X`
+    ///
+    /// Codes starting with `X` aren't derived from a country code. They
+    /// denote supranational currencies (e.g. `XAF`), precious metals, units
+    /// of account, the testing code `XTS` and `XXX` for no currency. Codes
+    /// in this block which aren't assigned by ISO are free for private use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert!(Currency::is_x_code("XAU"));
+    /// assert!(Currency::is_x_code("XBT"));
+    /// assert!(!Currency::is_x_code("EUR"));
+    /// assert!(!Currency::is_x_code("Xau"));
+    /// ```
+    pub fn is_x_code(code: &str) -> bool {
+        Self::is_wellformed_code(code) && code.starts_with('X')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+
+    #[test]
+    fn wellformed_code() {
+        assert!(Currency::is_wellformed_code("AAA"));
+        assert!(!Currency::is_wellformed_code(""));
+        assert!(!Currency::is_wellformed_code("A1A"));
+        assert!(!Currency::is_wellformed_code("ÄAA"));
+        assert!(!Currency::is_wellformed_code(" EUR"));
+    }
+
+    #[test]
+    fn reserved_numeric() {
+        assert!(Currency::is_reserved_numeric(900));
+        assert!(!Currency::is_reserved_numeric(899));
+        assert!(!Currency::is_reserved_numeric(0));
+        assert!(!Currency::is_reserved_numeric(1000));
+        assert!(!Currency::NUMERIC_RANGE.contains(&0));
+        assert!(Currency::is_reserved_numeric(Currency::XXX.numeric()));
+    }
+}
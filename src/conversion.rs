@@ -0,0 +1,298 @@
+//! Converting amounts between currencies with fees and rounding rules
+
+use std::convert::TryFrom;
+
+use crate::{ArithmeticError, Currency, Ratio};
+
+/// How a converted amount is rounded to a whole minor unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Rounds to the nearest value, ties away from zero
+    HalfAwayFromZero,
+    /// Rounds to the nearest value, ties to the even neighbour (banker's rounding)
+    HalfEven,
+    /// Rounds towards zero, i.e. truncates
+    TowardZero,
+    /// Rounds away from zero
+    AwayFromZero,
+    /// Rounds towards negative infinity
+    Floor,
+    /// Rounds towards positive infinity
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// Divides `numerator` by the positive `denominator` with this rounding
+    fn div(self, numerator: i128, denominator: i128) -> i128 {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        if remainder == 0 {
+            return quotient;
+        }
+        let away = quotient + numerator.signum();
+        // the distances to the two neighbours, doubling the remainder could overflow
+        let below = remainder.abs();
+        let above = denominator - below;
+        let round_away = match self {
+            RoundingMode::HalfAwayFromZero => below >= above,
+            RoundingMode::HalfEven => below > above || (below == above && quotient % 2 != 0),
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => numerator < 0,
+            RoundingMode::Ceiling => numerator > 0,
+        };
+        match round_away {
+            true => away,
+            false => quotient,
+        }
+    }
+}
+
+/// How an amount is converted between currencies
+///
+/// The amount is first converted at the exchange rate and rounded to
+/// `intermediate_precision` decimal places of the target currency (at least
+/// its exponent). The spread is then deducted and the result rounded to the
+/// target currency's minor unit, before the fee is deducted. Both roundings
+/// use `rounding`.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{ConversionPolicy, RoundingMode};
+///
+/// let policy = ConversionPolicy {
+///     spread_bps: 150,
+///     fee_minor: 99,
+///     ..ConversionPolicy::new(RoundingMode::HalfEven)
+/// };
+/// assert_eq!(policy.intermediate_precision, 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConversionPolicy {
+    /// The spread deducted from the converted amount in basis points (1/100 of a percent)
+    pub spread_bps: u16,
+    /// A flat fee in minor units of the target currency
+    pub fee_minor: i64,
+    /// The rounding applied to intermediate and final amounts
+    pub rounding: RoundingMode,
+    /// The number of decimal places kept before the spread is deducted
+    pub intermediate_precision: u16,
+}
+
+impl ConversionPolicy {
+    /// Creates a policy without spread and fee, keeping 6 intermediate decimal places
+    pub const fn new(rounding: RoundingMode) -> Self {
+        ConversionPolicy {
+            spread_bps: 0,
+            fee_minor: 0,
+            rounding,
+            intermediate_precision: 6,
+        }
+    }
+}
+
+impl Default for ConversionPolicy {
+    fn default() -> Self {
+        ConversionPolicy::new(RoundingMode::HalfAwayFromZero)
+    }
+}
+
+fn pow10(exponent: u16) -> Result<i128, ArithmeticError> {
+    10_i128
+        .checked_pow(u32::from(exponent))
+        .ok_or(ArithmeticError::Overflow)
+}
+
+/// Converts an amount in minor units of `from` to minor units of `to`
+///
+/// `rate` is the price of one unit of `from` in units of `to`, e.g.
+/// `Ratio::new(16245, 100)` for 162.45 JPY per EUR. The exponents of both
+/// currencies are taken into account, currencies without a minor unit are
+/// treated as having an exponent of 0. See `ConversionPolicy` for how the
+/// spread, fee and rounding are applied.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{convert, ConversionPolicy, Currency, Ratio, RoundingMode};
+///
+/// // 10.00 EUR at 162.45 JPY per EUR are 1624.5 JPY
+/// let rate = Ratio::new(16245, 100);
+/// let half_up = ConversionPolicy::new(RoundingMode::HalfAwayFromZero);
+/// let half_even = ConversionPolicy::new(RoundingMode::HalfEven);
+/// assert_eq!(convert(1000, Currency::EUR, Currency::JPY, rate, &half_up), Ok(1625));
+/// assert_eq!(convert(1000, Currency::EUR, Currency::JPY, rate, &half_even), Ok(1624));
+///
+/// // 1% spread and a fee of 0.500 KWD
+/// let policy = ConversionPolicy {
+///     spread_bps: 100,
+///     fee_minor: 500,
+///     ..ConversionPolicy::default()
+/// };
+/// // 100.00 USD at 0.3075 KWD per USD
+/// let rate = Ratio::new(3075, 10000);
+/// assert_eq!(convert(10000, Currency::USD, Currency::KWD, rate, &policy), Ok(29943));
+/// ```
+pub fn convert(
+    amount: i64,
+    from: Currency,
+    to: Currency,
+    rate: Ratio,
+    policy: &ConversionPolicy,
+) -> Result<i64, ArithmeticError> {
+    if rate.denominator == 0 {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+    let from_exponent = from.exponent().unwrap_or(0);
+    let to_exponent = to.exponent().unwrap_or(0);
+    let precision = policy.intermediate_precision.max(to_exponent);
+
+    // amount * rate in units of `to` with `precision` decimal places
+    let gross = i128::from(amount)
+        .checked_mul(i128::from(rate.numerator))
+        .and_then(|v| v.checked_mul(pow10(precision).ok()?))
+        .ok_or(ArithmeticError::Overflow)?;
+    let divisor = i128::from(rate.denominator)
+        .checked_mul(pow10(from_exponent)?)
+        .ok_or(ArithmeticError::Overflow)?;
+    let gross = policy.rounding.div(gross, divisor);
+
+    let kept = 10_000_i128 - i128::from(policy.spread_bps.min(10_000));
+    let net = gross.checked_mul(kept).ok_or(ArithmeticError::Overflow)?;
+    let divisor = pow10(precision - to_exponent)?
+        .checked_mul(10_000)
+        .ok_or(ArithmeticError::Overflow)?;
+    let net = policy.rounding.div(net, divisor);
+
+    net.checked_sub(i128::from(policy.fee_minor))
+        .and_then(|v| i64::try_from(v).ok())
+        .ok_or(ArithmeticError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundingMode;
+    use crate::{convert, ArithmeticError, ConversionPolicy, Currency, Ratio};
+
+    #[test]
+    fn rounding_modes() {
+        let cases = [
+            (RoundingMode::HalfAwayFromZero, [3, 2, -2, -3]),
+            (RoundingMode::HalfEven, [2, 2, -2, -2]),
+            (RoundingMode::TowardZero, [2, 2, -2, -2]),
+            (RoundingMode::AwayFromZero, [3, 3, -3, -3]),
+            (RoundingMode::Floor, [2, 2, -3, -3]),
+            (RoundingMode::Ceiling, [3, 3, -2, -2]),
+        ];
+        for (mode, expected) in cases.iter() {
+            // 2.5, 2.25, -2.25, -2.5
+            let rounded = [
+                mode.div(10, 4),
+                mode.div(9, 4),
+                mode.div(-9, 4),
+                mode.div(-10, 4),
+            ];
+            assert_eq!(&rounded, expected, "{:?}", mode);
+        }
+        assert_eq!(RoundingMode::HalfEven.div(14, 4), 4);
+        assert_eq!(RoundingMode::Floor.div(-8, 4), -2);
+        // remainders above i128::MAX / 2
+        let large = 10_i128.pow(38);
+        assert_eq!(RoundingMode::HalfAwayFromZero.div(large / 10 * 9, large), 1);
+        assert_eq!(RoundingMode::HalfEven.div(-large / 2, large), 0);
+    }
+
+    #[test]
+    fn convert_exponents() {
+        let policy = ConversionPolicy::default();
+        // 1000 JPY at 0.0061 EUR per JPY
+        let rate = Ratio::new(61, 10_000);
+        assert_eq!(
+            convert(1000, Currency::JPY, Currency::EUR, rate, &policy),
+            Ok(610)
+        );
+        // 1.000 BHD at 2.65 USD per BHD
+        let rate = Ratio::new(265, 100);
+        assert_eq!(
+            convert(1000, Currency::BHD, Currency::USD, rate, &policy),
+            Ok(265)
+        );
+        assert_eq!(
+            convert(-1000, Currency::BHD, Currency::USD, rate, &policy),
+            Ok(-265)
+        );
+    }
+
+    #[test]
+    fn convert_intermediate_precision() {
+        // 0.01 EUR at 1.0049 USD per EUR with a 10% spread
+        let rate = Ratio::new(10049, 10_000);
+        let mut policy = ConversionPolicy {
+            spread_bps: 1000,
+            ..ConversionPolicy::new(RoundingMode::HalfAwayFromZero)
+        };
+        // 0.010049 * 0.9 = 0.0090441
+        assert_eq!(
+            convert(1, Currency::EUR, Currency::USD, rate, &policy),
+            Ok(1)
+        );
+        // 0.01 * 0.9 = 0.009
+        policy.intermediate_precision = 0;
+        assert_eq!(
+            convert(1, Currency::EUR, Currency::USD, rate, &policy),
+            Ok(1)
+        );
+        policy.rounding = RoundingMode::TowardZero;
+        assert_eq!(
+            convert(1, Currency::EUR, Currency::USD, rate, &policy),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn convert_large_intermediate_precision() {
+        let rate = Ratio::new(1, 1);
+        let mut policy = ConversionPolicy {
+            intermediate_precision: 34,
+            ..ConversionPolicy::default()
+        };
+        assert_eq!(
+            convert(1, Currency::JPY, Currency::JPY, rate, &policy),
+            Ok(1)
+        );
+        policy.intermediate_precision = 36;
+        assert_eq!(
+            convert(0, Currency::JPY, Currency::JPY, rate, &policy),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+
+    #[test]
+    fn convert_errors() {
+        let policy = ConversionPolicy::default();
+        assert_eq!(
+            convert(1, Currency::EUR, Currency::USD, Ratio::new(1, 0), &policy),
+            Err(ArithmeticError::DivisionByZero)
+        );
+        assert_eq!(
+            convert(
+                i64::MAX,
+                Currency::EUR,
+                Currency::USD,
+                Ratio::new(u64::MAX, 1),
+                &policy
+            ),
+            Err(ArithmeticError::Overflow)
+        );
+        let policy = ConversionPolicy {
+            fee_minor: i64::MIN,
+            ..policy
+        };
+        assert_eq!(
+            convert(1, Currency::EUR, Currency::USD, Ratio::new(1, 1), &policy),
+            Err(ArithmeticError::Overflow)
+        );
+    }
+}
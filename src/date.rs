@@ -0,0 +1,217 @@
+//! A minimal calendar date used by the crate's historical data
+
+#[cfg(any(feature = "with-chrono", feature = "with-time"))]
+use std::convert::TryFrom;
+use std::fmt;
+
+/// A calendar date in the proleptic Gregorian calendar
+///
+/// This is intentionally a plain value type without any calendar
+/// arithmetic, so that the crate's data doesn't depend on a date crate.
+/// Dates are ordered chronologically and displayed as `YYYY-MM-DD`.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Date;
+///
+/// let date = Date::new(2023, 1, 1);
+/// assert_eq!(date.to_string(), "2023-01-01");
+/// assert!(Date::new(2022, 12, 31) < date);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new date, the values are not validated
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Date { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Error returned when converting between `Date` and a date crate fails
+///
+/// Either the `Date` isn't a valid calendar date or the other date's year
+/// doesn't fit into a `u16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("date out of range")]
+pub struct DateRangeError;
+
+/// A date type which can be used with the crate's date bearing APIs
+///
+/// Implemented for `Date` and, behind the `with-chrono` and `with-time`
+/// features, for `chrono::NaiveDate` and `time::Date`, so APIs like
+/// `CurrencyTimeline::currency_at` accept the date type already used by the
+/// application.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, Date, DateAdapter};
+///
+/// fn replaced_on<D: DateAdapter>(currency: Currency) -> Option<D> {
+///     currency.superseded_on()?.convert().ok()
+/// }
+///
+/// assert_eq!(replaced_on::<Date>(Currency::HRK), Some(Date::new(2023, 1, 1)));
+/// assert_eq!(Date::new(2023, 1, 1).to_date(), Ok(Date::new(2023, 1, 1)));
+/// ```
+pub trait DateAdapter: Sized {
+    /// Converts the date to the crate's `Date`
+    fn to_date(&self) -> Result<Date, DateRangeError>;
+
+    /// Converts the crate's `Date` to this date type
+    fn from_date(date: Date) -> Result<Self, DateRangeError>;
+}
+
+impl DateAdapter for Date {
+    fn to_date(&self) -> Result<Date, DateRangeError> {
+        Ok(*self)
+    }
+
+    fn from_date(date: Date) -> Result<Self, DateRangeError> {
+        Ok(date)
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+impl DateAdapter for chrono::NaiveDate {
+    fn to_date(&self) -> Result<Date, DateRangeError> {
+        Date::try_from(*self)
+    }
+
+    fn from_date(date: Date) -> Result<Self, DateRangeError> {
+        chrono::NaiveDate::try_from(date)
+    }
+}
+
+#[cfg(feature = "with-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+impl DateAdapter for time::Date {
+    fn to_date(&self) -> Result<Date, DateRangeError> {
+        Date::try_from(*self)
+    }
+
+    fn from_date(date: Date) -> Result<Self, DateRangeError> {
+        time::Date::try_from(date)
+    }
+}
+
+impl Date {
+    /// Converts the date to another date type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Date;
+    ///
+    /// assert_eq!(Date::new(2023, 1, 1).convert::<Date>(), Ok(Date::new(2023, 1, 1)));
+    /// ```
+    pub fn convert<D: DateAdapter>(self) -> Result<D, DateRangeError> {
+        D::from_date(self)
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl TryFrom<Date> for chrono::NaiveDate {
+    type Error = DateRangeError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        chrono::NaiveDate::from_ymd_opt(
+            i32::from(date.year),
+            u32::from(date.month),
+            u32::from(date.day),
+        )
+        .ok_or(DateRangeError)
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = DateRangeError;
+
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+
+        let year = u16::try_from(date.year()).map_err(|_| DateRangeError)?;
+        Ok(Date::new(year, date.month() as u8, date.day() as u8))
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl TryFrom<Date> for time::Date {
+    type Error = DateRangeError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let month = time::Month::try_from(date.month).map_err(|_| DateRangeError)?;
+        time::Date::from_calendar_date(i32::from(date.year), month, date.day)
+            .map_err(|_| DateRangeError)
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl TryFrom<time::Date> for Date {
+    type Error = DateRangeError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        let year = u16::try_from(date.year()).map_err(|_| DateRangeError)?;
+        Ok(Date::new(year, u8::from(date.month()), date.day()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "with-chrono", feature = "with-time"))]
+    use super::{Date, DateAdapter, DateRangeError};
+    #[cfg(any(feature = "with-chrono", feature = "with-time"))]
+    use std::convert::TryFrom;
+
+    #[test]
+    fn display() {
+        assert_eq!(super::Date::new(999, 3, 7).to_string(), "0999-03-07");
+    }
+
+    #[cfg(feature = "with-chrono")]
+    #[test]
+    fn chrono() {
+        let date = chrono::NaiveDate::try_from(Date::new(2024, 2, 29)).unwrap();
+        assert_eq!(date, chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(Date::try_from(date), Ok(Date::new(2024, 2, 29)));
+        assert_eq!(
+            chrono::NaiveDate::try_from(Date::new(2023, 2, 29)),
+            Err(DateRangeError)
+        );
+        let ancient = chrono::NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert_eq!(Date::try_from(ancient), Err(DateRangeError));
+        assert_eq!(ancient.to_date(), Err(DateRangeError));
+        assert_eq!(Date::new(2024, 2, 29).convert(), Ok(date));
+    }
+
+    #[cfg(feature = "with-time")]
+    #[test]
+    fn time() {
+        let date = time::Date::try_from(Date::new(2023, 1, 1)).unwrap();
+        assert_eq!(
+            date,
+            time::Date::from_calendar_date(2023, time::Month::January, 1).unwrap()
+        );
+        assert_eq!(Date::try_from(date), Ok(Date::new(2023, 1, 1)));
+        assert_eq!(
+            time::Date::try_from(Date::new(2023, 13, 1)),
+            Err(DateRangeError)
+        );
+        assert_eq!(date.to_date(), Ok(Date::new(2023, 1, 1)));
+        assert_eq!(Date::new(2023, 1, 1).convert(), Ok(date));
+    }
+}
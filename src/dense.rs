@@ -0,0 +1,52 @@
+//! Contiguous indices for using currencies as array indices
+
+use crate::Currency;
+
+impl Currency {
+    /// The number of currencies, all dense indices are below it
+    pub const COUNT: usize = Currency::VARIANTS.len();
+
+    /// Returns the index of the currency in the range `0..Currency::COUNT`
+    ///
+    /// Indices follow the order of the `Currency` enum and cover the range
+    /// without gaps, so they can index arrays, e.g. across an FFI boundary or
+    /// in columnar analytics. They are only stable for the same crate version
+    /// built with the same data, a release adding currencies or a build
+    /// filtered with `ISO_CURRENCY_COUNTRIES` assigns different indices. Use
+    /// the code or the numeric code to persist currencies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let mut totals = [0_i64; Currency::COUNT];
+    /// totals[Currency::EUR.dense_index()] += 1250;
+    /// assert_eq!(Currency::from_dense_index(Currency::EUR.dense_index()), Some(Currency::EUR));
+    /// assert_eq!(Currency::from_dense_index(Currency::COUNT), None);
+    /// ```
+    pub fn dense_index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the currency with the given dense index
+    ///
+    /// Returns `None` if the index isn't below `Currency::COUNT`.
+    pub fn from_dense_index(index: usize) -> Option<Currency> {
+        Currency::VARIANTS.get(index).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+
+    #[test]
+    fn dense_index() {
+        for (i, currency) in Currency::VARIANTS.iter().enumerate() {
+            assert_eq!(currency.dense_index(), i);
+            assert_eq!(Currency::from_dense_index(i), Some(*currency));
+        }
+        assert_eq!(Currency::COUNT, Currency::VARIANTS.len());
+    }
+}
@@ -0,0 +1,86 @@
+//! The crate wide error type
+
+use crate::{
+    ActiveCurrencyError, ArithmeticError, DateRangeError, IndexError, LineError,
+    ParseCurrencyError, ParseFlagError, ParseKindError, ParseSymbolError,
+};
+
+/// Any error returned by this crate
+///
+/// Every fallible API returns its own specific error type, all of which can
+/// be converted into this enum with `?`. The specific error is available
+/// through `std::error::Error::source`.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, Error};
+///
+/// fn total(code: &str, a: i64, b: i64) -> Result<i64, Error> {
+///     let currency: Currency = code.parse()?;
+///     Ok(currency.checked_add_minor(a, b, 2)?)
+/// }
+///
+/// assert!(matches!(total("EUR", i64::MAX, 1), Err(Error::Arithmetic(_))));
+/// assert!(matches!(total("AAA", 1, 1), Err(Error::Parse(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A currency code couldn't be parsed
+    #[error("failed to parse currency")]
+    Parse(#[from] ParseCurrencyError),
+    /// A currency code isn't of a currency in circulation
+    #[error("currency is not in circulation")]
+    Active(#[from] ActiveCurrencyError),
+    /// A currency symbol couldn't be parsed
+    #[error("failed to parse currency symbol")]
+    ParseSymbol(#[from] ParseSymbolError),
+    /// A kind identifier couldn't be parsed
+    #[error("failed to parse currency kind")]
+    ParseKind(#[from] ParseKindError),
+    /// A flag identifier couldn't be parsed
+    #[error("failed to parse currency flag")]
+    ParseFlag(#[from] ParseFlagError),
+    /// A minor unit calculation failed
+    #[error("failed to calculate amount")]
+    Arithmetic(#[from] ArithmeticError),
+    /// A date couldn't be converted
+    #[error("failed to convert date")]
+    Date(#[from] DateRangeError),
+    /// A line of a stream of currency codes couldn't be read or validated
+    #[error("failed to validate line")]
+    Line(#[from] LineError),
+    /// An amount couldn't be adjusted with a price index
+    #[error("failed to adjust amount")]
+    Index(#[from] IndexError),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArithmeticError, Date, DateAdapter, Error, ParseCurrencyError};
+    use std::error::Error as _;
+
+    #[test]
+    fn source_chaining() {
+        let error = Error::from(ArithmeticError::Overflow);
+        assert_eq!(error.to_string(), "failed to calculate amount");
+        assert_eq!(error.source().unwrap().to_string(), "arithmetic overflow");
+
+        let error = Error::from(ParseCurrencyError);
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "not a valid ISO 4217 currency code"
+        );
+    }
+
+    #[test]
+    fn date() {
+        let convert = |date: Date| -> Result<Date, Error> { Ok(date.to_date()?) };
+        assert_eq!(convert(Date::new(2023, 1, 1)), Ok(Date::new(2023, 1, 1)));
+
+        let error = Error::from(crate::DateRangeError);
+        assert_eq!(error.to_string(), "failed to convert date");
+        assert_eq!(error.source().unwrap().to_string(), "date out of range");
+    }
+}
@@ -0,0 +1,122 @@
+//! Stable identifiers for flags
+//!
+//! Flags are displayed, parsed and serialized as `fund`, `special` and
+//! `superseded:<code>` (e.g. `superseded:VED`). These identifiers are stable
+//! across releases, so rule engines and configuration files can refer to them.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Currency, Flag};
+
+/// Error returned when parsing an unknown flag identifier
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("not a known currency flag")]
+pub struct ParseFlagError;
+
+impl fmt::Display for Flag {
+    /// Writes the stable identifier of the flag
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Currency, Flag};
+    ///
+    /// assert_eq!(Flag::Fund.to_string(), "fund");
+    /// assert_eq!(Flag::Superseded(Currency::VED).to_string(), "superseded:VED");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Flag::Fund => f.write_str("fund"),
+            Flag::Special => f.write_str("special"),
+            Flag::Superseded(successor) => write!(f, "superseded:{}", successor.code()),
+        }
+    }
+}
+
+impl FromStr for Flag {
+    type Err = ParseFlagError;
+
+    /// Parses the stable identifier of a flag
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Currency, Flag};
+    ///
+    /// assert_eq!("special".parse(), Ok(Flag::Special));
+    /// assert_eq!("superseded:VED".parse(), Ok(Flag::Superseded(Currency::VED)));
+    /// assert!("superseded".parse::<Flag>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fund" => Ok(Flag::Fund),
+            "special" => Ok(Flag::Special),
+            _ => s
+                .strip_prefix("superseded:")
+                .and_then(Currency::from_code)
+                .map(Flag::Superseded)
+                .ok_or(ParseFlagError),
+        }
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for Flag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl<'de> serde::Deserialize<'de> for Flag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let identifier = std::borrow::Cow::<str>::deserialize(deserializer)?;
+        identifier.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseFlagError;
+    use crate::{Currency, Flag};
+
+    #[test]
+    fn identifiers() {
+        for flag in [Flag::Fund, Flag::Special, Flag::Superseded(Currency::EUR)].iter() {
+            assert_eq!(flag.to_string().parse().as_ref(), Ok(flag));
+        }
+        // these identifiers are stable, changing them breaks stored configs
+        assert_eq!(Flag::Special.to_string(), "special");
+        assert_eq!(
+            Flag::Superseded(Currency::VED).to_string(),
+            "superseded:VED"
+        );
+        assert_eq!("Fund".parse::<Flag>(), Err(ParseFlagError));
+        assert_eq!("superseded:".parse::<Flag>(), Err(ParseFlagError));
+        assert_eq!("superseded:AAA".parse::<Flag>(), Err(ParseFlagError));
+        assert_eq!("superseded:ved".parse::<Flag>(), Err(ParseFlagError));
+    }
+
+    #[test]
+    fn currency_flags() {
+        let ids: Vec<String> = Currency::VES.flags().iter().map(Flag::to_string).collect();
+        assert_eq!(ids, vec!["superseded:VED"]);
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn serde() {
+        let flags = vec![Flag::Fund, Flag::Superseded(Currency::VED)];
+        let json = serde_json::to_string(&flags).unwrap();
+        assert_eq!(json, r#"["fund","superseded:VED"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Flag>>(&json).unwrap(), flags);
+        assert!(serde_json::from_str::<Flag>(r#""superseded:XYZ""#).is_err());
+    }
+}
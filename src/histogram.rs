@@ -0,0 +1,187 @@
+//! Counting currencies for statistics
+
+use std::iter::FromIterator;
+
+use crate::Currency;
+
+/// Number of occurrences of each currency
+///
+/// Collect an iterator of currencies, e.g. of the transactions of a period,
+/// to get the counts, the most used currencies and each currency's share.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, CurrencyHistogram};
+///
+/// let histogram: CurrencyHistogram = vec![Currency::EUR, Currency::USD, Currency::EUR, Currency::EUR]
+///     .into_iter()
+///     .collect();
+///
+/// assert_eq!(histogram.count(Currency::EUR), 3);
+/// assert_eq!(histogram.total(), 4);
+/// assert_eq!(histogram.top(1), vec![(Currency::EUR, 3)]);
+/// assert_eq!(histogram.percentage(Currency::USD), 25.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyHistogram {
+    // indexed by `Currency::dense_index`
+    counts: [u64; Currency::COUNT],
+    total: u64,
+}
+
+impl Default for CurrencyHistogram {
+    fn default() -> Self {
+        CurrencyHistogram {
+            counts: [0; Currency::COUNT],
+            total: 0,
+        }
+    }
+}
+
+impl CurrencyHistogram {
+    /// Creates a histogram without any counts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts one occurrence of a currency
+    pub fn add(&mut self, currency: Currency) {
+        self.add_count(currency, 1);
+    }
+
+    /// Counts `count` occurrences of a currency
+    pub fn add_count(&mut self, currency: Currency, count: u64) {
+        self.counts[currency.dense_index()] += count;
+        self.total += count;
+    }
+
+    /// Returns the number of occurrences of a currency
+    pub fn count(&self, currency: Currency) -> u64 {
+        self.counts[currency.dense_index()]
+    }
+
+    /// Returns the number of occurrences of all currencies
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of currencies which occurred at least once
+    pub fn distinct(&self) -> usize {
+        self.counts.iter().filter(|count| **count > 0).count()
+    }
+
+    /// Returns true if no currency was counted
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+
+    /// Returns the currencies which occurred with their counts, in the order of the `Currency` enum
+    pub fn iter(&self) -> impl Iterator<Item = (Currency, u64)> + '_ {
+        Currency::VARIANTS
+            .iter()
+            .zip(self.counts.iter())
+            .filter(|(_, count)| **count > 0)
+            .map(|(currency, count)| (*currency, *count))
+    }
+
+    /// Returns the `n` most frequent currencies with their counts
+    ///
+    /// Currencies with the same count are ordered by code.
+    pub fn top(&self, n: usize) -> Vec<(Currency, u64)> {
+        let mut counts: Vec<(Currency, u64)> = self.iter().collect();
+        counts.sort_by_key(|(currency, count)| (std::cmp::Reverse(*count), *currency));
+        counts.truncate(n);
+        counts
+    }
+
+    /// Returns the share of a currency in percent of the total, 0 if nothing was counted
+    pub fn percentage(&self, currency: Currency) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => self.count(currency) as f64 * 100.0 / total as f64,
+        }
+    }
+
+    /// Returns the share in percent of every currency which occurred, most frequent first
+    ///
+    /// Currencies with the same count are ordered by code. The percentages
+    /// add up to 100 apart from floating point rounding.
+    pub fn percentages(&self) -> Vec<(Currency, f64)> {
+        self.top(Currency::COUNT)
+            .into_iter()
+            .map(|(currency, _)| (currency, self.percentage(currency)))
+            .collect()
+    }
+}
+
+impl Extend<Currency> for CurrencyHistogram {
+    fn extend<I: IntoIterator<Item = Currency>>(&mut self, iter: I) {
+        for currency in iter {
+            self.add(currency);
+        }
+    }
+}
+
+impl FromIterator<Currency> for CurrencyHistogram {
+    fn from_iter<I: IntoIterator<Item = Currency>>(iter: I) -> Self {
+        let mut histogram = CurrencyHistogram::new();
+        histogram.extend(iter);
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, CurrencyHistogram};
+
+    #[test]
+    fn empty() {
+        let histogram = CurrencyHistogram::new();
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.distinct(), 0);
+        assert_eq!(histogram.top(3), vec![]);
+        assert_eq!(histogram.percentage(Currency::EUR), 0.0);
+        assert!(histogram.percentages().is_empty());
+    }
+
+    #[test]
+    fn counts() {
+        let mut histogram: CurrencyHistogram = vec![Currency::USD, Currency::JPY, Currency::EUR]
+            .into_iter()
+            .collect();
+        histogram.add_count(Currency::USD, 4);
+        histogram.extend(vec![Currency::JPY]);
+
+        assert_eq!(histogram.total(), 8);
+        assert_eq!(histogram.distinct(), 3);
+        assert_eq!(histogram.count(Currency::CHF), 0);
+        assert_eq!(
+            histogram.iter().collect::<Vec<_>>(),
+            vec![(Currency::EUR, 1), (Currency::JPY, 2), (Currency::USD, 5)]
+        );
+        assert_eq!(
+            histogram.top(5),
+            vec![(Currency::USD, 5), (Currency::JPY, 2), (Currency::EUR, 1)]
+        );
+    }
+
+    #[test]
+    fn percentages() {
+        let histogram: CurrencyHistogram = vec![Currency::GBP, Currency::CHF, Currency::CHF]
+            .into_iter()
+            .chain(vec![Currency::AUD])
+            .collect();
+        let percentages = histogram.percentages();
+        assert_eq!(
+            percentages,
+            vec![
+                (Currency::CHF, 50.0),
+                (Currency::AUD, 25.0),
+                (Currency::GBP, 25.0)
+            ]
+        );
+        let sum: f64 = percentages.iter().map(|(_, p)| p).sum();
+        assert!((sum - 100.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,151 @@
+//! Adjusting amounts with a price index
+//!
+//! Adjusting for inflation needs a yearly index level per currency, usually
+//! a consumer price index published by a national statistics office. Such
+//! series are revised regularly, so [`IndexProvider`] leaves their source to
+//! the caller and [`Currency::adjust`] only applies the ratio of two levels.
+
+use std::convert::TryFrom;
+
+use crate::{ArithmeticError, Currency};
+
+/// A source of yearly price index levels
+///
+/// Index levels are integers so they can be applied exactly, scale them up
+/// as needed (e.g. `104_250` for an index of `104.25`). Only the ratio between
+/// two levels of the same currency is used.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, IndexProvider};
+///
+/// struct FlatIndex;
+///
+/// impl IndexProvider for FlatIndex {
+///     fn index(&self, _currency: Currency, _year: u16) -> Option<u64> {
+///         Some(100)
+///     }
+/// }
+///
+/// assert_eq!(Currency::EUR.adjust(1000, 2000, 2020, &FlatIndex), Ok(1000));
+/// ```
+pub trait IndexProvider {
+    /// Returns the index level of `currency` in `year`, if known
+    fn index(&self, currency: Currency, year: u16) -> Option<u64>;
+}
+
+/// Error returned by `Currency::adjust`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum IndexError {
+    /// The provider has no index level for the currency in the given year
+    #[error("no index level for {currency:?} in {year}")]
+    Missing { currency: Currency, year: u16 },
+    /// Applying the index levels failed
+    #[error("failed to apply index")]
+    Arithmetic(#[from] ArithmeticError),
+}
+
+impl Currency {
+    /// Adjusts an amount in the currency's minor units from `from_year` to `to_year`
+    ///
+    /// The amount is multiplied by the ratio of the two index levels and
+    /// rounded half away from zero to a whole minor unit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Currency, IndexError, IndexProvider};
+    ///
+    /// struct Cpi;
+    ///
+    /// impl IndexProvider for Cpi {
+    ///     fn index(&self, _currency: Currency, year: u16) -> Option<u64> {
+    ///         match year {
+    ///             2000 => Some(80_000),
+    ///             2020 => Some(100_000),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // 10.00 EUR in 2000 are 12.50 EUR in 2020
+    /// assert_eq!(Currency::EUR.adjust(1000, 2000, 2020, &Cpi), Ok(1250));
+    /// assert_eq!(
+    ///     Currency::EUR.adjust(1000, 1990, 2020, &Cpi),
+    ///     Err(IndexError::Missing { currency: Currency::EUR, year: 1990 })
+    /// );
+    /// ```
+    pub fn adjust<P: IndexProvider + ?Sized>(
+        self,
+        amount: i64,
+        from_year: u16,
+        to_year: u16,
+        provider: &P,
+    ) -> Result<i64, IndexError> {
+        let level = |year| {
+            let index = provider.index(self, year).ok_or(IndexError::Missing {
+                currency: self,
+                year,
+            })?;
+            i64::try_from(index).map_err(|_| IndexError::from(ArithmeticError::Overflow))
+        };
+        let from = level(from_year)?;
+        let to = level(to_year)?;
+        Ok(self.mul_div_round(amount, self.exponent().unwrap_or(0), to, from)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ArithmeticError, Currency, IndexError, IndexProvider};
+
+    /// A series of a single currency, starting in `first_year`
+    struct Series {
+        currency: Currency,
+        first_year: u16,
+        levels: Vec<u64>,
+    }
+
+    impl IndexProvider for Series {
+        fn index(&self, currency: Currency, year: u16) -> Option<u64> {
+            if currency != self.currency {
+                return None;
+            }
+            let offset = year.checked_sub(self.first_year)?;
+            self.levels.get(usize::from(offset)).copied()
+        }
+    }
+
+    #[test]
+    fn adjust() {
+        let mut levels = vec![3; 13];
+        levels[10] = 4;
+        levels[11] = 0;
+        levels[12] = u64::MAX;
+        let cpi = Series {
+            currency: Currency::JPY,
+            first_year: 2010,
+            levels,
+        };
+
+        assert_eq!(Currency::JPY.adjust(100, 2010, 2020, &cpi), Ok(133));
+        assert_eq!(Currency::JPY.adjust(-100, 2020, 2010, &cpi), Ok(-75));
+        assert_eq!(Currency::JPY.adjust(100, 2010, 2010, &cpi), Ok(100));
+        assert_eq!(
+            Currency::USD.adjust(100, 2010, 2020, &cpi),
+            Err(IndexError::Missing {
+                currency: Currency::USD,
+                year: 2010
+            })
+        );
+        assert_eq!(
+            Currency::JPY.adjust(100, 2021, 2020, &cpi),
+            Err(IndexError::Arithmetic(ArithmeticError::DivisionByZero))
+        );
+        assert_eq!(
+            Currency::JPY.adjust(100, 2010, 2022, &cpi),
+            Err(IndexError::Arithmetic(ArithmeticError::Overflow))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Picking a currency from request metadata
+
+use std::str::FromStr;
+
+use crate::{Country, Currency};
+
+/// Converts an alphabetic region subtag to a country, numeric regions are ignored
+fn country_from_region(region: &str) -> Option<Country> {
+    match region.len() == 2 && region.bytes().all(|b| b.is_ascii_alphabetic()) {
+        true => Country::from_str(&region.to_ascii_uppercase()).ok(),
+        false => None,
+    }
+}
+
+/// Returns the region subtag of a BCP 47 language tag
+fn region_of(tag: &str) -> Option<Country> {
+    let mut subtags = tag.split(['-', '_']).skip(1).peekable();
+    // the optional extended language subtags are 3 letters, e.g. `zh-yue-HK`
+    while subtags
+        .next_if(|s| s.len() == 3 && s.bytes().all(|b| b.is_ascii_alphabetic()))
+        .is_some()
+    {}
+    // the optional script subtag is 4 letters, so the region follows it
+    subtags.next_if(|s| s.len() == 4);
+    subtags.next().and_then(country_from_region)
+}
+
+/// A locale which may specify a region
+///
+/// Implemented for BCP 47 language tags given as `str` and, behind the
+/// `with-unic-langid` and `with-oxilangtag` features, for the parsed locale
+/// types of those crates so they don't need to be converted to strings.
+pub trait LocaleRegion {
+    /// Returns the region of the locale, if it has one
+    fn region(&self) -> Option<Country>;
+}
+
+impl LocaleRegion for str {
+    fn region(&self) -> Option<Country> {
+        region_of(self)
+    }
+}
+
+#[cfg(feature = "with-unic-langid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-unic-langid")))]
+impl LocaleRegion for unic_langid::LanguageIdentifier {
+    fn region(&self) -> Option<Country> {
+        self.region
+            .as_ref()
+            .and_then(|region| country_from_region(region.as_str()))
+    }
+}
+
+#[cfg(feature = "with-oxilangtag")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-oxilangtag")))]
+impl<T: std::ops::Deref<Target = str>> LocaleRegion for oxilangtag::LanguageTag<T> {
+    fn region(&self) -> Option<Country> {
+        oxilangtag::LanguageTag::region(self).and_then(country_from_region)
+    }
+}
+
+/// Returns the regions of an `Accept-Language` header ordered by preference
+fn preferred_regions(accept_language: &str) -> Vec<Country> {
+    let mut regions: Vec<(f32, Country)> = accept_language
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            // entries with a malformed weight, e.g. `q=NaN` or `q=2`, are skipped
+            let quality = match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(q) => q
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|q| (0.0..=1.0).contains(q))?,
+                None => 1.0,
+            };
+            if quality == 0.0 {
+                return None;
+            }
+            Some((quality, region_of(tag)?))
+        })
+        .collect();
+    // the sort is stable so equal weights keep the header order
+    regions.sort_by(|a, b| b.0.total_cmp(&a.0));
+    regions.into_iter().map(|(_, region)| region).collect()
+}
+
+impl Currency {
+    /// Returns the regular currency of a locale's region
+    ///
+    /// Locales without a region, or with a region that has no regular
+    /// currency, return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(Currency::for_locale("de-AT"), Some(Currency::EUR));
+    /// assert_eq!(Currency::for_locale("de"), None);
+    /// ```
+    pub fn for_locale<L: LocaleRegion + ?Sized>(locale: &L) -> Option<Currency> {
+        locale.region().and_then(Currency::regular_for_country)
+    }
+
+    /// Infers the currency to use for a request
+    ///
+    /// The currency is resolved in the following order:
+    ///
+    /// 1. The regular currency of `country_hint` (e.g. from GeoIP or the
+    ///    shipping address), if given.
+    /// 2. The regular currency of the region of each language tag in
+    ///    `accept_language`, by descending quality value. Tags without a
+    ///    region (e.g. `de`) are skipped as the language alone doesn't
+    ///    determine a currency.
+    /// 3. `fallback`.
+    ///
+    /// The regular currency of a country is the one returned by
+    /// `Currency::from(Country)`, a country without one (e.g. Antarctica)
+    /// is skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Country, Currency};
+    ///
+    /// let header = Some("de;q=0.9, fr-CH;q=0.8, en-US;q=0.5");
+    /// assert_eq!(Currency::infer(header, None, Currency::EUR), Currency::CHF);
+    /// assert_eq!(Currency::infer(header, Some(Country::JP), Currency::EUR), Currency::JPY);
+    /// assert_eq!(Currency::infer(Some("de"), None, Currency::EUR), Currency::EUR);
+    /// ```
+    pub fn infer(
+        accept_language: Option<&str>,
+        country_hint: Option<Country>,
+        fallback: Currency,
+    ) -> Currency {
+        country_hint
+            .into_iter()
+            .chain(accept_language.map(preferred_regions).unwrap_or_default())
+            .find_map(Currency::regular_for_country)
+            .unwrap_or(fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{preferred_regions, region_of};
+    use crate::{Country, Currency};
+
+    #[test]
+    fn parses_regions() {
+        assert_eq!(region_of("en-GB"), Some(Country::GB));
+        assert_eq!(region_of("zh-Hant-tw"), Some(Country::TW));
+        assert_eq!(region_of("en_US"), Some(Country::US));
+        assert_eq!(region_of("zh-yue-HK"), Some(Country::HK));
+        assert_eq!(region_of("zh-cmn-Hans-CN"), Some(Country::CN));
+        assert_eq!(region_of("sgn-ase-US"), Some(Country::US));
+        assert_eq!(region_of("es-419"), None);
+        assert_eq!(region_of("en"), None);
+        assert_eq!(region_of("*"), None);
+    }
+
+    #[test]
+    fn for_locale() {
+        assert_eq!(Currency::for_locale("sr-Latn-RS"), Some(Currency::RSD));
+        assert_eq!(Currency::for_locale("en-AQ"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-unic-langid")]
+    fn for_unic_langid() {
+        let locale: unic_langid::LanguageIdentifier = "fr-CA".parse().unwrap();
+        assert_eq!(Currency::for_locale(&locale), Some(Currency::CAD));
+        let locale: unic_langid::LanguageIdentifier = "es-419".parse().unwrap();
+        assert_eq!(Currency::for_locale(&locale), None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-oxilangtag")]
+    fn for_oxilangtag() {
+        let locale = oxilangtag::LanguageTag::parse("pt-BR").unwrap();
+        assert_eq!(Currency::for_locale(&locale), Some(Currency::BRL));
+        let locale = oxilangtag::LanguageTag::parse("pt").unwrap();
+        assert_eq!(Currency::for_locale(&locale), None);
+    }
+
+    #[test]
+    fn orders_by_quality() {
+        assert_eq!(
+            preferred_regions("en-US;q=0.5, sv-SE, da-DK;q=0.7, nb-NO;q=0"),
+            vec![Country::SE, Country::DK, Country::US]
+        );
+        assert_eq!(
+            preferred_regions("fr-FR;q=NaN, de-DE;q=inf, it-IT;q=1.5, en-US;q=-1, sv-SE;q=0.1"),
+            vec![Country::SE]
+        );
+    }
+
+    #[test]
+    fn infer() {
+        assert_eq!(
+            Currency::infer(Some("en-GB,en;q=0.9"), None, Currency::USD),
+            Currency::GBP
+        );
+        assert_eq!(
+            Currency::infer(Some("en-AQ, sv-SE;q=0.5"), None, Currency::USD),
+            Currency::SEK
+        );
+        assert_eq!(
+            Currency::infer(Some("en-GB"), Some(Country::AQ), Currency::USD),
+            Currency::GBP
+        );
+        assert_eq!(Currency::infer(None, None, Currency::USD), Currency::USD);
+    }
+}
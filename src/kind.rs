@@ -0,0 +1,173 @@
+//! Grouping of currencies by kind for exhaustive handling
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The kind of a currency
+///
+/// Every currency has exactly one kind. A superseded fund or special
+/// currency is of kind `Superseded`.
+///
+/// This enum is deliberately not `#[non_exhaustive]`: if a new kind is ever
+/// added, matches over it (e.g. through `match_currency!`) stop compiling
+/// instead of silently falling into a default branch.
+///
+/// Kinds are displayed, parsed and serialized as the lowercase identifiers
+/// `regular`, `fund`, `special` and `superseded`. These identifiers are
+/// stable across releases, so they can be stored in configuration files.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Kind;
+///
+/// assert_eq!(Kind::Fund.to_string(), "fund");
+/// assert_eq!("superseded".parse(), Ok(Kind::Superseded));
+/// assert!("Fund".parse::<Kind>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "with-serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Kind {
+    /// A regular circulating currency
+    Regular,
+    /// A fund, see `Currency::is_fund`
+    Fund,
+    /// A special currency, see `Currency::is_special`
+    Special,
+    /// A currency replaced by another one, see `Currency::is_superseded`
+    Superseded,
+}
+
+impl Kind {
+    /// Returns the stable identifier of the kind
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Kind::Regular => "regular",
+            Kind::Fund => "fund",
+            Kind::Special => "special",
+            Kind::Superseded => "superseded",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an unknown kind identifier
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("not a known currency kind")]
+pub struct ParseKindError;
+
+impl FromStr for Kind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "regular" => Ok(Kind::Regular),
+            "fund" => Ok(Kind::Fund),
+            "special" => Ok(Kind::Special),
+            "superseded" => Ok(Kind::Superseded),
+            _ => Err(ParseKindError),
+        }
+    }
+}
+
+/// Exhaustively matches a currency by its kind
+///
+/// Every `Kind` has to be handled and a wildcard arm isn't accepted, so code
+/// like fee engines can't silently default currencies of a kind they never
+/// considered. Currencies added to an already handled kind are picked up
+/// automatically.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{match_currency, Currency};
+///
+/// fn fee_bps(currency: Currency) -> u32 {
+///     match_currency!(currency, {
+///         Regular => 25,
+///         Fund | Special => 100,
+///         Superseded => 0,
+///     })
+/// }
+///
+/// assert_eq!(fee_bps(Currency::EUR), 25);
+/// assert_eq!(fee_bps(Currency::XAU), 100);
+/// ```
+///
+/// Leaving out a kind is a compile error:
+///
+/// ```compile_fail
+/// use iso_currency::{match_currency, Currency};
+///
+/// let fee = match_currency!(Currency::EUR, {
+///     Regular => 25,
+///     Fund => 100,
+/// });
+/// ```
+#[macro_export]
+macro_rules! match_currency {
+    ($currency:expr, { $($($kind:ident)|+ => $body:expr),+ $(,)? }) => {
+        match $crate::Currency::kind($currency) {
+            $($($crate::Kind::$kind)|+ => $body),+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseKindError;
+    use crate::{Currency, Kind};
+
+    #[test]
+    fn kind() {
+        assert_eq!(Currency::USD.kind(), Kind::Regular);
+        assert_eq!(Currency::CLF.kind(), Kind::Fund);
+        assert_eq!(Currency::XXX.kind(), Kind::Special);
+        assert_eq!(Currency::VES.kind(), Kind::Superseded);
+    }
+
+    #[test]
+    fn match_currency() {
+        let describe = |currency| {
+            match_currency!(currency, {
+                Regular => "regular",
+                Fund => "fund",
+                Special | Superseded => "other",
+            })
+        };
+        assert_eq!(describe(Currency::JPY), "regular");
+        assert_eq!(describe(Currency::MXV), "fund");
+        assert_eq!(describe(Currency::CUC), "other");
+    }
+
+    #[test]
+    fn identifiers() {
+        for kind in [Kind::Regular, Kind::Fund, Kind::Special, Kind::Superseded].iter() {
+            assert_eq!(kind.to_string().parse(), Ok(*kind));
+        }
+        // these identifiers are stable, changing them breaks stored configs
+        assert_eq!(Kind::Regular.as_str(), "regular");
+        assert_eq!(Kind::Superseded.as_str(), "superseded");
+        assert_eq!("".parse::<Kind>(), Err(ParseKindError));
+        assert_eq!("REGULAR".parse::<Kind>(), Err(ParseKindError));
+    }
+
+    #[cfg(feature = "with-serde")]
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&Kind::Fund).unwrap(), r#""fund""#);
+        assert_eq!(
+            serde_json::from_str::<Kind>(r#""special""#).unwrap(),
+            Kind::Special
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Mapping currencies to an organization's own numeric ids
+
+use crate::{Currency, LEGACY_IDS};
+
+impl Currency {
+    /// Returns the legacy id of the currency
+    ///
+    /// Legacy ids are read when the crate is built from the tab separated
+    /// file given in the `ISO_CURRENCY_LEGACY_IDS` environment variable, with
+    /// a header line and the columns `currency` (the code) and `id`. The build
+    /// fails unless every currency has exactly one unique id. Without the
+    /// variable this always returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// if let Some(id) = Currency::EUR.legacy_id() {
+    ///     assert_eq!(Currency::from_legacy_id(id), Some(Currency::EUR));
+    /// }
+    /// ```
+    pub fn legacy_id(self) -> Option<u32> {
+        LEGACY_IDS
+            .iter()
+            .find(|(_, currency)| *currency == self)
+            .map(|(id, _)| *id)
+    }
+
+    /// Returns the currency with the given legacy id
+    ///
+    /// See `Currency::legacy_id` for how to provide the ids.
+    pub fn from_legacy_id(id: u32) -> Option<Currency> {
+        LEGACY_IDS
+            .binary_search_by_key(&id, |(id, _)| *id)
+            .ok()
+            .map(|i| LEGACY_IDS[i].1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, LEGACY_IDS};
+
+    #[test]
+    fn legacy_ids() {
+        assert!(LEGACY_IDS.is_empty() || LEGACY_IDS.len() == Currency::COUNT);
+        for currency in Currency::VARIANTS {
+            match currency.legacy_id() {
+                Some(id) => assert_eq!(Currency::from_legacy_id(id), Some(*currency)),
+                None => assert!(LEGACY_IDS.is_empty()),
+            }
+        }
+    }
+}
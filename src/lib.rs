@@ -8,7 +8,7 @@
 //! The data for this is taken from
 //! [https://en.wikipedia.org/wiki/ISO_4217](https://en.wikipedia.org/wiki/ISO_4217)
 //!
-//! The `Country` enum is re-exported from the only dependency - the [iso_country](https://crates.io/crates/iso_country) crate.
+//! The `Country` enum is re-exported from the [iso_country](https://crates.io/crates/iso_country) crate.
 //!
 //! # Examples
 //!
@@ -30,7 +30,7 @@
 //! assert_eq!(Currency::VES.is_superseded(), Some(Currency::VED));
 //! assert_eq!(Currency::VED.is_superseded(), None);
 //! assert_eq!(Currency::VES.latest(), Currency::VED);
-//! assert_eq!(Currency::BOV.flags(), vec![iso_currency::Flag::Fund]);
+//! assert_eq!(Currency::BOV.flags(), &[iso_currency::Flag::Fund]);
 //! ```
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -52,21 +52,99 @@ pub use strum::IntoEnumIterator;
 
 include!(concat!(env!("OUT_DIR"), "/isodata.rs"));
 
-#[derive(PartialEq, Eq)]
+mod accepted;
+mod active;
+mod arithmetic;
+#[cfg(feature = "data-artifacts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "data-artifacts")))]
+pub mod artifacts;
+mod as_code;
+#[cfg(feature = "bench-data")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bench-data")))]
+pub mod bench_data;
+mod build_info;
+mod catalog;
+#[cfg(feature = "with-ciborium")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-ciborium")))]
+pub mod cbor;
+mod codes;
+mod conversion;
+mod date;
+mod dense;
+mod error;
+mod flag;
+mod histogram;
+mod index;
+mod infer;
+mod kind;
+mod legacy;
+#[cfg(feature = "market-data")]
+mod market_data;
+mod overrides;
+mod providers;
+mod rates;
+#[cfg(feature = "with-serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-serde")))]
+pub mod remap;
+#[cfg(feature = "with-schemars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-schemars")))]
+pub mod schema_check;
+mod script;
+mod search;
+#[cfg(feature = "with-serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-serde_with")))]
+pub mod serde_as;
+#[cfg(feature = "with-serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-serde")))]
+pub mod serde_helpers;
+mod stream;
+mod symbol;
+mod timeline;
+mod usage;
+#[cfg(any(feature = "with-validator", feature = "with-garde"))]
+mod validate;
+#[cfg(feature = "verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verification")))]
+pub mod verification;
+
+pub use active::ActiveCurrencyError;
+pub use arithmetic::{ArithmeticError, Ratio};
+pub use as_code::AsCode;
+pub use build_info::{build_info, BuildInfo};
+#[cfg(feature = "embed-json")]
+pub use catalog::catalog_json;
+pub use catalog::{catalog_etag, SCHEMA_JSON};
+pub use conversion::{convert, ConversionPolicy, RoundingMode};
+pub use date::{Date, DateAdapter, DateRangeError};
+pub use error::Error;
+pub use flag::ParseFlagError;
+pub use histogram::CurrencyHistogram;
+pub use index::{IndexError, IndexProvider};
+pub use infer::LocaleRegion;
+pub use kind::{Kind, ParseKindError};
+pub use overrides::{CountryCurrencyOverrides, SymbolOverride, SymbolOverrides};
+pub use providers::Psp;
+pub use rates::{RateSnapshot, SharedRateTable};
+pub use script::Script;
+pub use stream::{validate_stream, LineError};
+pub use symbol::ParseSymbolError;
+pub use timeline::{CurrencyPeriod, CurrencyTimeline};
+pub use usage::UsageStats;
+#[cfg(feature = "with-garde")]
+pub use validate::garde_code;
+#[cfg(feature = "with-validator")]
+pub use validate::validate_code;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct CurrencySymbol {
-    pub symbol: String,
-    pub subunit_symbol: Option<String>,
+    pub symbol: &'static str,
+    pub subunit_symbol: Option<&'static str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("not a valid ISO 4217 currency code")]
 pub struct ParseCurrencyError;
 
-impl std::fmt::Display for ParseCurrencyError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "not a valid ISO 4217 currency code")
-    }
-}
-
 impl std::fmt::Debug for CurrencySymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.symbol)
@@ -85,12 +163,68 @@ impl CurrencySymbol {
     /// Data for the symbols was collected from
     /// [https://en.wikipedia.org/wiki/Currency_symbol#List_of_presently-circulating_currency_symbols]()
     ///
-    pub fn new(symbol: &str, subunit_symbol: Option<&str>) -> CurrencySymbol {
+    pub const fn new(symbol: &'static str, subunit_symbol: Option<&'static str>) -> CurrencySymbol {
         CurrencySymbol {
-            symbol: symbol.to_owned(),
-            subunit_symbol: subunit_symbol.map(|v| v.to_owned()),
+            symbol,
+            subunit_symbol,
         }
     }
+
+    /// Returns true if both the symbol and subunit symbol are in Unicode
+    /// Normalization Form C (NFC)
+    ///
+    /// All symbols returned by `Currency::symbol` are normalized to NFC when
+    /// the crate is built, so this only returns false for symbols
+    /// constructed manually from non-normalized strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Currency, CurrencySymbol};
+    ///
+    /// assert!(Currency::CZK.symbol().is_nfc());
+    /// assert!(!CurrencySymbol::new("K\u{30c}c", None).is_nfc());
+    /// ```
+    pub fn is_nfc(&self) -> bool {
+        unicode_normalization::is_nfc(self.symbol)
+            && self
+                .subunit_symbol
+                .iter()
+                .all(|v| unicode_normalization::is_nfc(v))
+    }
+
+    /// Returns the Unicode code points of the symbol
+    ///
+    /// Text rendering layers can use this to check whether a font covers
+    /// the symbol, e.g. for newer signs like `₼` or `₸`, and fall back to the
+    /// code otherwise. The subunit symbol isn't included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let codepoints: Vec<char> = Currency::AZN.symbol().codepoints().collect();
+    /// assert_eq!(codepoints, vec!['\u{20bc}']);
+    /// ```
+    pub fn codepoints(&self) -> std::str::Chars<'static> {
+        self.symbol.chars()
+    }
+
+    /// Returns true if the symbol is a single Unicode code point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert!(Currency::AZN.symbol().is_single_codepoint());
+    /// assert!(!Currency::BRL.symbol().is_single_codepoint());
+    /// ```
+    pub fn is_single_codepoint(&self) -> bool {
+        let mut codepoints = self.codepoints();
+        codepoints.next().is_some() && codepoints.next().is_none()
+    }
 }
 
 impl std::fmt::Debug for Currency {
@@ -105,6 +239,40 @@ impl std::fmt::Display for Currency {
     }
 }
 
+// Hash follows the code so it agrees with `Borrow<str>`, the derived Ord
+// already does as the variants are sorted by code
+impl std::hash::Hash for Currency {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.code().hash(state)
+    }
+}
+
+impl AsRef<str> for Currency {
+    /// Returns the code of the currency
+    fn as_ref(&self) -> &str {
+        self.code()
+    }
+}
+
+impl std::borrow::Borrow<str> for Currency {
+    /// Returns the code of the currency, so maps keyed by `Currency` can be
+    /// queried with a `&str`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut balances = HashMap::new();
+    /// balances.insert(Currency::EUR, 100);
+    /// assert_eq!(balances.get("EUR"), Some(&100));
+    /// ```
+    fn borrow(&self) -> &str {
+        self.code()
+    }
+}
+
 impl std::str::FromStr for Currency {
     type Err = ParseCurrencyError;
 
@@ -117,7 +285,9 @@ impl std::str::FromStr for Currency {
 }
 
 /// Extra information for a currency
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// See `Display` and `FromStr` for the stable identifiers of flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Flag {
     /// The currency is a fund
     Fund,
@@ -134,10 +304,167 @@ impl From<Country> for Currency {
     /// All currencies who are superseded by another currency are filtered out.
     /// Same goes for funds and special currencies.
     fn from(country: Country) -> Self {
+        Self::regular_for_country(country).unwrap()
+    }
+}
+
+impl Currency {
+    /// Returns an iterator over all currencies ordered by numeric code
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let first: Vec<Currency> = Currency::iter_numeric_order().take(2).collect();
+    /// assert_eq!(first, vec![Currency::ALL, Currency::DZD]);
+    /// ```
+    pub fn iter_numeric_order() -> impl Iterator<Item = Currency> {
+        Currency::NUMERIC_ORDER.iter().copied()
+    }
+
+    /// The first currency of a country which isn't superseded, a fund or special
+    pub(crate) fn regular_for_country(country: Country) -> Option<Currency> {
         Self::from_country(country)
             .into_iter()
             .find(|c| c.flags().is_empty())
-            .unwrap()
+    }
+
+    /// Returns a message describing what superseded the currency
+    ///
+    /// Meant for error responses to clients sending outdated codes. The date
+    /// is left out if it isn't known. Returns `None` if the currency isn't
+    /// superseded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(
+    ///     Currency::HRK.superseded_message().as_deref(),
+    ///     Some("HRK was replaced by EUR on 2023-01-01")
+    /// );
+    /// assert_eq!(Currency::EUR.superseded_message(), None);
+    /// ```
+    pub fn superseded_message(self) -> Option<String> {
+        let successor = self.is_superseded()?;
+        Some(match self.superseded_on() {
+            Some(date) => format!(
+                "{} was replaced by {} on {}",
+                self.code(),
+                successor.code(),
+                date
+            ),
+            None => format!("{} was replaced by {}", self.code(), successor.code()),
+        })
+    }
+
+    /// Returns a multi-line summary of the currency for logs and diagnostics
+    ///
+    /// The format is meant for humans and might change between releases.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(
+    ///     Currency::HRK.describe(),
+    ///     "code: HRK\n\
+    ///      numeric: 191\n\
+    ///      name: Croatian kuna\n\
+    ///      exponent: 2\n\
+    ///      countries: HR\n\
+    ///      flags: superseded\n\
+    ///      superseded by: EUR on 2023-01-01 at 2000/15069"
+    /// );
+    /// ```
+    pub fn describe(self) -> String {
+        let list = |items: Vec<String>| match items.is_empty() {
+            true => "none".to_owned(),
+            false => items.join(", "),
+        };
+        let exponent = self
+            .exponent()
+            .map_or_else(|| "none".to_owned(), |e| e.to_string());
+        let countries = list(self.used_by().iter().map(|c| c.to_string()).collect());
+        let flags = list(
+            self.flags()
+                .iter()
+                .map(|flag| match flag {
+                    Flag::Fund => "fund".to_owned(),
+                    Flag::Special => "special".to_owned(),
+                    Flag::Superseded(_) => "superseded".to_owned(),
+                })
+                .collect(),
+        );
+
+        let mut description = format!(
+            "code: {}\nnumeric: {:03}\nname: {}\nexponent: {}\ncountries: {}\nflags: {}",
+            self.code(),
+            self.numeric(),
+            self.name(),
+            exponent,
+            countries,
+            flags
+        );
+        if let Some(successor) = self.is_superseded() {
+            description.push_str("\nsuperseded by: ");
+            description.push_str(successor.code());
+            if let Some(date) = self.superseded_on() {
+                description.push_str(&format!(" on {}", date));
+            }
+            // the factor is only known towards the latest currency
+            if let (latest, Some(ratio)) = self.latest_with_factor() {
+                if latest == successor {
+                    description.push_str(&format!(" at {}", ratio));
+                }
+            }
+        }
+        description
+    }
+
+    /// Writes the three letter code without allocating
+    ///
+    /// Together with `write_numeric_padded` this is meant for fixed width
+    /// records (e.g. ISO 8583 or NACHA files) where the currency occupies
+    /// exactly three columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let mut record = String::from("AMT000000012345");
+    /// Currency::EUR.write_code(&mut record).unwrap();
+    /// assert_eq!(record, "AMT000000012345EUR");
+    /// ```
+    pub fn write_code<W: std::fmt::Write + ?Sized>(self, w: &mut W) -> std::fmt::Result {
+        w.write_str(self.code())
+    }
+
+    /// Writes the numeric code padded with zeros to three digits without allocating
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let mut field = String::new();
+    /// Currency::ALL.write_numeric_padded(&mut field).unwrap();
+    /// assert_eq!(field, "008");
+    /// ```
+    pub fn write_numeric_padded<W: std::fmt::Write + ?Sized>(self, w: &mut W) -> std::fmt::Result {
+        write!(w, "{:03}", self.numeric())
+    }
+}
+
+#[cfg(feature = "with-defmt")]
+impl defmt::Format for Currency {
+    /// Formats the currency as its code, e.g. `EUR`
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.code())
     }
 }
 
@@ -191,7 +518,9 @@ impl sqlx::Type<sqlx::MySql> for Currency {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Country, Currency, Flag, ParseCurrencyError};
+    use crate::{
+        Country, Currency, CurrencySymbol, CurrencyTimeline, Date, Flag, ParseCurrencyError, Ratio,
+    };
 
     #[cfg(feature = "with-serde")]
     use std::collections::HashMap;
@@ -215,6 +544,33 @@ mod tests {
         assert_eq!(Currency::EUR.code(), "EUR");
     }
 
+    #[test]
+    fn return_code_lowercase() {
+        assert_eq!(Currency::EUR.code_lowercase(), "eur");
+        assert_eq!(Currency::XXX.as_url_segment(), "xxx");
+    }
+
+    #[test]
+    fn stable_hash64() {
+        assert_eq!(Currency::USD.stable_hash64(), 0x61eeb119db28ec07);
+
+        let fnv1a64 = |code: &str| {
+            code.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            })
+        };
+        assert_eq!(Currency::XXX.stable_hash64(), fnv1a64("XXX"));
+        assert_eq!(Currency::BGN.stable_hash64(), fnv1a64("BGN"));
+    }
+
+    #[test]
+    fn from_url_segment() {
+        assert_eq!(Currency::from_url_segment("sek"), Some(Currency::SEK));
+        assert_eq!(Currency::from_url_segment("SEK"), Some(Currency::SEK));
+        assert_eq!(Currency::from_url_segment("aaa"), None);
+        assert_eq!(Currency::from_url_segment("sek/"), None);
+    }
+
     #[test]
     fn from_code() {
         assert_eq!(Currency::from_code("EUR"), Some(Currency::EUR));
@@ -247,6 +603,92 @@ mod tests {
         assert_eq!(format!("{}", Currency::AED.symbol()), "د.إ");
     }
 
+    #[test]
+    fn symbol_is_nfc() {
+        assert!(Currency::KGS.symbol().is_nfc());
+        assert!(Currency::CZK.symbol().is_nfc());
+        assert!(!CurrencySymbol::new("Kc\u{30c}", None).is_nfc());
+        assert!(!CurrencySymbol::new("$", Some("c\u{327}")).is_nfc());
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn iter_is_ordered_by_code() {
+        use crate::IntoEnumIterator;
+        let codes: Vec<&str> = Currency::iter().map(|c| c.code()).collect();
+        let mut sorted = codes.clone();
+        sorted.sort_unstable();
+        assert_eq!(codes, sorted);
+        assert_eq!(codes.len(), Currency::COUNT);
+    }
+
+    #[test]
+    fn iter_numeric_order() {
+        let numeric: Vec<u16> = Currency::iter_numeric_order()
+            .map(|c| c.numeric())
+            .collect();
+        assert_eq!(numeric.len(), Currency::COUNT);
+        assert!(numeric.windows(2).all(|w| w[0] < w[1]));
+        assert!(Currency::VARIANTS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "iterator")]
+    fn all_symbols_are_nfc() {
+        use crate::IntoEnumIterator;
+        assert!(Currency::iter().all(|c| c.symbol().is_nfc()));
+    }
+
+    #[test]
+    fn symbol_as_map_key() {
+        use std::collections::HashSet;
+
+        let symbols: HashSet<CurrencySymbol> = [Currency::USD, Currency::CAD, Currency::EUR]
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert_eq!(symbols.len(), 2);
+
+        let symbol = Currency::EUR.symbol();
+        let copy = symbol;
+        assert_eq!(symbol, copy);
+        assert!(CurrencySymbol::new("$", None) < CurrencySymbol::new("€", None));
+    }
+
+    #[test]
+    fn multi_currency_countries() {
+        let countries = crate::multi_currency_countries();
+        assert!(countries
+            .iter()
+            .any(|(country, currencies)| *country == Country::BT
+                && *currencies == [Currency::BTN, Currency::INR]));
+        // CUC is superseded, so Cuba has a single circulating currency
+        assert!(countries.iter().all(|(country, _)| *country != Country::CU));
+        assert!(countries.iter().all(|(_, currencies)| currencies.len() > 1));
+    }
+
+    #[test]
+    fn symbol_collisions() {
+        let (symbol, currencies) = crate::symbol_collisions()
+            .iter()
+            .find(|(s, _)| s.symbol == "£")
+            .unwrap();
+        assert_eq!(symbol.subunit_symbol, None);
+        assert_eq!(
+            *currencies,
+            &[
+                Currency::EGP,
+                Currency::FKP,
+                Currency::GBP,
+                Currency::GIP,
+                Currency::SHP
+            ]
+        );
+        assert!(crate::symbol_collisions()
+            .iter()
+            .all(|(s, _)| s.symbol != "€"));
+    }
+
     #[test]
     fn subunit_fraction() {
         assert_eq!(Currency::EUR.subunit_fraction(), Some(100));
@@ -268,6 +710,31 @@ mod tests {
         assert_eq!(Currency::MRU.exponent(), Some(2));
     }
 
+    #[test]
+    fn with_exponent() {
+        assert_eq!(Currency::with_exponent(4), &[Currency::CLF, Currency::UYW]);
+        for exponent in 0..=4 {
+            assert!(Currency::with_exponent(exponent)
+                .iter()
+                .all(|c| c.exponent() == Some(u16::from(exponent))));
+        }
+    }
+
+    #[test]
+    fn group_consts() {
+        assert_eq!(Currency::ZERO_DECIMAL, Currency::with_exponent(0));
+        assert_eq!(Currency::FOUR_DECIMAL, &[Currency::CLF, Currency::UYW]);
+        assert!(Currency::THREE_DECIMAL.contains(&Currency::BHD));
+        assert!(Currency::FUNDS.iter().all(|c| c.is_fund()));
+        assert_eq!(
+            Currency::METALS,
+            &[Currency::XAG, Currency::XAU, Currency::XPD, Currency::XPT]
+        );
+        assert!(Currency::SPECIAL.iter().all(|c| c.is_special()));
+        assert!(Currency::SUPERSEDED.contains(&Currency::HRK));
+        assert!(!Currency::SUPERSEDED.contains(&Currency::EUR));
+    }
+
     #[test]
     #[cfg(feature = "with-serde")]
     fn deserialize() {
@@ -336,18 +803,114 @@ mod tests {
         assert_eq!(Currency::VES.latest(), Currency::VED);
     }
 
+    #[test]
+    fn test_latest_with_factor() {
+        assert_eq!(
+            Currency::HRK.latest_with_factor(),
+            (Currency::EUR, Some(Ratio::new(2000, 15069)))
+        );
+        assert_eq!(
+            Currency::SLL.latest_with_factor(),
+            (Currency::SLE, Some(Ratio::new(1, 1000)))
+        );
+        assert_eq!(Currency::VED.latest_with_factor(), (Currency::VED, None));
+    }
+
+    #[test]
+    fn introduced() {
+        assert_eq!(Currency::SLE.introduced(), Some(Date::new(2022, 7, 1)));
+        assert_eq!(Currency::XAU.introduced(), None);
+        for currency in [Currency::HRK, Currency::SLL, Currency::VES, Currency::ZWL] {
+            assert!(currency.introduced() < currency.superseded_on());
+        }
+        assert_eq!(Currency::CAD.introduced(), Some(Date::new(1858, 1, 1)));
+        for currency in Currency::VARIANTS {
+            if !currency.is_fund() && !currency.is_special() {
+                assert!(currency.introduced().is_some(), "{:?}", currency);
+            }
+        }
+    }
+
+    #[cfg(feature = "with-rkyv")]
+    #[test]
+    fn rkyv_round_trip() {
+        let currencies = vec![Currency::EUR, Currency::JPY, Currency::XXX];
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&currencies).unwrap();
+
+        let archived =
+            rkyv::access::<rkyv::Archived<Vec<Currency>>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived[0], crate::ArchivedCurrency::EUR);
+
+        let deserialized =
+            rkyv::deserialize::<Vec<Currency>, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(deserialized, currencies);
+    }
+
+    #[cfg(feature = "impl-default")]
+    #[test]
+    fn default() {
+        #[derive(Default)]
+        struct Config {
+            currency: Currency,
+        }
+
+        assert_eq!(Config::default().currency, Currency::XXX);
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            Currency::CHF.describe(),
+            "code: CHF\nnumeric: 756\nname: Swiss franc\nexponent: 2\ncountries: LI, CH\nflags: none"
+        );
+        assert!(Currency::XAU.describe().contains("\nexponent: none\n"));
+        assert!(Currency::BOV.describe().ends_with("\nflags: fund"));
+    }
+
+    #[test]
+    fn superseded_message() {
+        assert_eq!(
+            Currency::VES.superseded_message().as_deref(),
+            Some("VES was replaced by VED on 2021-10-01")
+        );
+        assert_eq!(
+            Currency::CUC.superseded_message().as_deref(),
+            Some("CUC was replaced by CUP on 2021-01-01")
+        );
+        assert_eq!(Currency::VED.superseded_message(), None);
+    }
+
     #[test]
     fn test_flags() {
-        assert_eq!(Currency::BOV.flags(), vec![Flag::Fund]);
-        assert_eq!(Currency::XBA.flags(), vec![Flag::Special]);
-        assert_eq!(Currency::VES.flags(), vec![Flag::Superseded(Currency::VED)]);
-        assert_eq!(Currency::VED.flags(), vec![]);
+        assert_eq!(Currency::BOV.flags(), &[Flag::Fund]);
+        assert_eq!(Currency::XBA.flags(), &[Flag::Special]);
+        assert_eq!(Currency::VES.flags(), &[Flag::Superseded(Currency::VED)]);
+        assert!(Currency::VED.flags().is_empty());
     }
 
     #[test]
     fn test_has_flag() {
         assert!(Currency::BOV.has_flag(Flag::Fund));
         assert!(!Currency::XBA.has_flag(Flag::Fund));
+        assert!(Currency::VES.has_flag(Flag::Superseded(Currency::VED)));
+        assert!(!Currency::VED.has_flag(Flag::Superseded(Currency::VED)));
+        for currency in Currency::VARIANTS.iter() {
+            for flag in [
+                Flag::Fund,
+                Flag::Special,
+                Flag::Superseded(currency.latest()),
+            ]
+            .iter()
+            {
+                assert_eq!(
+                    currency.has_flag(*flag),
+                    currency.flags().contains(flag),
+                    "{:?} {}",
+                    currency,
+                    flag
+                );
+            }
+        }
     }
 
     #[test]
@@ -364,4 +927,109 @@ mod tests {
         assert_eq!(Currency::from(Country::AF), Currency::AFN);
         assert_eq!(Currency::from(Country::IO), Currency::GBP);
     }
+
+    #[test]
+    fn test_fixed_width_writers() {
+        let mut record = String::new();
+        for currency in [Currency::USD, Currency::XXX, Currency::BHD].iter() {
+            currency.write_numeric_padded(&mut record).unwrap();
+            currency.write_code(&mut record).unwrap();
+        }
+        assert_eq!(record, "840USD999XXX048BHD");
+    }
+
+    #[test]
+    fn test_symbol_codepoints() {
+        assert_eq!(Currency::EUR.symbol().codepoints().collect::<String>(), "€");
+        assert!(Currency::EUR.symbol().is_single_codepoint());
+        assert!(!CurrencySymbol::new("", None).is_single_codepoint());
+        assert!(!CurrencySymbol::new("K\u{30c}c", None).is_single_codepoint());
+    }
+
+    #[test]
+    fn test_code_lookups() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let prices: HashMap<String, u32> = vec![("EUR".to_owned(), 1)].into_iter().collect();
+        assert_eq!(prices.get(Currency::EUR.as_ref()), Some(&1));
+
+        let hashed: HashMap<Currency, u32> = vec![(Currency::JPY, 2)].into_iter().collect();
+        assert_eq!(hashed.get("JPY"), Some(&2));
+        assert_eq!(hashed.get("USD"), None);
+
+        let ordered: BTreeMap<Currency, u32> = vec![(Currency::USD, 3), (Currency::CHF, 4)]
+            .into_iter()
+            .collect();
+        assert_eq!(ordered.get("CHF"), Some(&4));
+        assert!(Currency::CHF < Currency::USD);
+    }
+
+    #[test]
+    fn test_issuing_countries() {
+        assert_eq!(Currency::USD.issuing_countries(), vec![Country::US]);
+        assert!(!Currency::GBP.issuing_countries().contains(&Country::IO));
+        assert!(!Currency::EUR.issuing_countries().contains(&Country::ME));
+        assert_eq!(Currency::XPF.issuing_countries(), Currency::XPF.used_by());
+        assert_eq!(Currency::XOF.issuing_countries(), Currency::XOF.used_by());
+        assert_eq!(Currency::XAU.issuing_countries(), vec![]);
+    }
+
+    #[test]
+    fn test_issuing_countries_of_euro_adopters() {
+        let issuers = Currency::EUR.issuing_countries();
+        for currency in Currency::VARIANTS {
+            if currency.is_superseded() != Some(Currency::EUR) {
+                continue;
+            }
+            let changeover = currency.superseded_on().unwrap();
+            for country in currency.used_by() {
+                let timeline = CurrencyTimeline::for_country(country);
+                assert_eq!(timeline.currency_at(changeover), Some(Currency::EUR));
+                assert!(issuers.contains(&country), "{} doesn't issue EUR", country);
+            }
+        }
+        assert!(issuers.contains(&Country::HR));
+        assert!(Currency::EUR.used_by().contains(&Country::HR));
+        assert_eq!(Currency::from(Country::HR), Currency::EUR);
+    }
+
+    #[test]
+    fn test_issuing_countries_use_the_currency() {
+        for currency in Currency::VARIANTS {
+            let used_by = currency.used_by();
+            for country in currency.issuing_countries() {
+                assert!(
+                    used_by.contains(&country),
+                    "{} isn't used in {}",
+                    currency.code(),
+                    country
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_blurb() {
+        assert!(Currency::EUR.blurb().unwrap().contains("eurozone"));
+        assert!(Currency::XXX.blurb().is_some());
+        assert_eq!(Currency::BOV.blurb(), None);
+        for currency in Currency::VARIANTS {
+            if !currency.is_fund() && !currency.is_special() && currency.is_superseded().is_none() {
+                assert!(currency.blurb().is_some(), "{:?}", currency);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "name-aliases")]
+    fn test_name_aliases() {
+        assert_eq!(Currency::EURO, Currency::EUR);
+        assert_eq!(Currency::UNITED_STATES_DOLLAR, Currency::USD);
+        assert_eq!(Currency::CFA_FRANC_BCEAO, Currency::XOF);
+        assert_eq!(Currency::POLISH_ZLOTY, Currency::PLN);
+        assert_eq!(Currency::VIETNAMESE_DONG, Currency::VND);
+        assert_eq!(Currency::CFP_FRANC, Currency::XPF);
+        assert_eq!(Currency::SIERRA_LEONEAN_LEONE, Currency::SLE);
+        assert_eq!(Currency::VENEZUELAN_BOLIVAR_SOBERANO, Currency::VED);
+    }
 }
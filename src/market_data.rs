@@ -0,0 +1,94 @@
+//! Conventions of market data vendors which differ from ISO 4217
+//!
+//! Market data vendors mostly use ISO 4217 codes, but quote some securities
+//! in a currency's minor unit and build instrument identifiers from the code.
+
+use crate::Currency;
+
+impl Currency {
+    /// Returns the Bloomberg code for prices quoted in the currency's minor unit
+    ///
+    /// Bloomberg marks minor unit quotes by lowercasing the last letter of
+    /// the code, e.g. London listed shares are priced in pence as `GBp`.
+    /// Returns `None` for currencies without such a code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(Currency::GBP.bloomberg_minor_code(), Some("GBp"));
+    /// assert_eq!(Currency::ZAR.bloomberg_minor_code(), Some("ZAr"));
+    /// assert_eq!(Currency::EUR.bloomberg_minor_code(), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "market-data")))]
+    pub fn bloomberg_minor_code(self) -> Option<&'static str> {
+        match self.code() {
+            "GBP" => Some("GBp"),
+            "ILS" => Some("ILs"),
+            "USD" => Some("USd"),
+            "ZAR" => Some("ZAr"),
+            _ => None,
+        }
+    }
+
+    /// Returns the Reuters (LSEG) spot rate RIC of currencies quoted in US dollars
+    ///
+    /// Spot rate RICs are the code followed by `=` and usually quote the
+    /// currency per US dollar, e.g. `JPY=` is the USD/JPY rate. A handful of
+    /// currencies and the precious metals are quoted the other way round,
+    /// e.g. `EUR=` is the EUR/USD rate. Returns the RIC of those exceptions
+    /// and `None` for all currencies following the usual convention.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(Currency::EUR.reuters_inverted_spot_ric(), Some("EUR="));
+    /// assert_eq!(Currency::XAU.reuters_inverted_spot_ric(), Some("XAU="));
+    /// assert_eq!(Currency::JPY.reuters_inverted_spot_ric(), None);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "market-data")))]
+    pub fn reuters_inverted_spot_ric(self) -> Option<&'static str> {
+        match self.code() {
+            "AUD" => Some("AUD="),
+            "EUR" => Some("EUR="),
+            "GBP" => Some("GBP="),
+            "NZD" => Some("NZD="),
+            "XAG" => Some("XAG="),
+            "XAU" => Some("XAU="),
+            "XPD" => Some("XPD="),
+            "XPT" => Some("XPT="),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+
+    #[test]
+    fn bloomberg_minor_code() {
+        for currency in Currency::VARIANTS {
+            if let Some(code) = currency.bloomberg_minor_code() {
+                assert!(code.eq_ignore_ascii_case(currency.code()));
+                assert_eq!(currency.exponent(), Some(2));
+            }
+        }
+        assert_eq!(Currency::ILS.bloomberg_minor_code(), Some("ILs"));
+    }
+
+    #[test]
+    fn reuters_inverted_spot_ric() {
+        for currency in Currency::VARIANTS {
+            if let Some(ric) = currency.reuters_inverted_spot_ric() {
+                assert_eq!(ric.strip_suffix('='), Some(currency.code()));
+            }
+        }
+        assert_eq!(Currency::NZD.reuters_inverted_spot_ric(), Some("NZD="));
+        assert_eq!(Currency::USD.reuters_inverted_spot_ric(), None);
+        assert_eq!(Currency::CHF.reuters_inverted_spot_ric(), None);
+    }
+}
@@ -0,0 +1,253 @@
+//! Runtime overrides of the currencies used by a country and of symbols
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Country, Currency, CurrencySymbol};
+
+/// Business specific exceptions to the country to currency mapping
+///
+/// Lookups first consult the overrides and fall back to the crate's data
+/// for any country without an override. Territories which aren't part of
+/// the `Country` enum (e.g. Kosovo, `XK`) can be added by their alpha-2 code.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Country, CountryCurrencyOverrides, Currency};
+///
+/// let mut overrides = CountryCurrencyOverrides::new();
+/// overrides.insert_code("XK", vec![Currency::EUR]);
+/// overrides.insert(Country::ZW, vec![Currency::USD, Currency::ZWG]);
+///
+/// assert_eq!(overrides.from_code("XK"), vec![Currency::EUR]);
+/// assert_eq!(overrides.regular_currency(Country::ZW), Some(Currency::USD));
+/// assert_eq!(overrides.regular_currency(Country::DE), Some(Currency::EUR));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CountryCurrencyOverrides {
+    countries: HashMap<String, Vec<Currency>>,
+}
+
+impl CountryCurrencyOverrides {
+    /// Creates an empty set of overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the currencies used in a country
+    ///
+    /// The currencies are ordered by preference, the first regular one is
+    /// returned by `CountryCurrencyOverrides::regular_currency`.
+    pub fn insert(&mut self, country: Country, currencies: Vec<Currency>) -> &mut Self {
+        self.insert_code(&country.to_string(), currencies)
+    }
+
+    /// Overrides the currencies used in a territory given by its ISO 3166-1 alpha-2 code
+    pub fn insert_code(&mut self, alpha2: &str, currencies: Vec<Currency>) -> &mut Self {
+        self.countries
+            .insert(alpha2.to_ascii_uppercase(), currencies);
+        self
+    }
+
+    /// Removes the override of a country, returning the overridden currencies
+    pub fn remove(&mut self, country: Country) -> Option<Vec<Currency>> {
+        self.countries.remove(&country.to_string())
+    }
+
+    /// Returns the currencies used in a country, like `Currency::from_country`
+    pub fn from_country(&self, country: Country) -> Vec<Currency> {
+        match self.countries.get(&country.to_string()) {
+            Some(currencies) => currencies.clone(),
+            None => Currency::from_country(country),
+        }
+    }
+
+    /// Returns the currencies used in a territory given by its ISO 3166-1 alpha-2 code
+    ///
+    /// Codes which are neither overridden nor known return an empty list.
+    pub fn from_code(&self, alpha2: &str) -> Vec<Currency> {
+        let alpha2 = alpha2.to_ascii_uppercase();
+        match self.countries.get(&alpha2) {
+            Some(currencies) => currencies.clone(),
+            None => Country::from_str(&alpha2)
+                .map(Currency::from_country)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the regular currency used in a country, like `Currency::from(Country)`
+    ///
+    /// Superseded currencies, funds and special currencies are skipped. This
+    /// returns `None` instead of panicking if there's no regular currency.
+    pub fn regular_currency(&self, country: Country) -> Option<Currency> {
+        self.from_country(country)
+            .into_iter()
+            .find(|c| c.flags().is_empty())
+    }
+}
+
+/// A symbol set in `SymbolOverrides`
+///
+/// Unlike `CurrencySymbol` it can own its strings, so symbols loaded at
+/// runtime (e.g. from a configuration file) can be used as overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolOverride {
+    pub symbol: Cow<'static, str>,
+    pub subunit_symbol: Option<Cow<'static, str>>,
+}
+
+impl SymbolOverride {
+    /// Creates a symbol without a subunit symbol
+    pub fn new<S: Into<Cow<'static, str>>>(symbol: S) -> Self {
+        SymbolOverride {
+            symbol: symbol.into(),
+            subunit_symbol: None,
+        }
+    }
+
+    /// Sets the subunit symbol
+    pub fn with_subunit<S: Into<Cow<'static, str>>>(mut self, subunit_symbol: S) -> Self {
+        self.subunit_symbol = Some(subunit_symbol.into());
+        self
+    }
+}
+
+impl From<CurrencySymbol> for SymbolOverride {
+    fn from(symbol: CurrencySymbol) -> Self {
+        SymbolOverride {
+            symbol: Cow::Borrowed(symbol.symbol),
+            subunit_symbol: symbol.subunit_symbol.map(Cow::Borrowed),
+        }
+    }
+}
+
+impl fmt::Display for SymbolOverride {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+/// Presentation specific symbols replacing the crate's data
+///
+/// Pass the registry to `Currency::symbol_with` wherever symbols are
+/// displayed. Currencies without an override keep the symbol returned by
+/// `Currency::symbol`.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, SymbolOverride, SymbolOverrides};
+///
+/// let configured = String::from("руб.");
+/// let mut overrides = SymbolOverrides::new();
+/// overrides.insert(Currency::USD, SymbolOverride::new("US$").with_subunit("¢"));
+/// overrides.insert(Currency::RUB, SymbolOverride::new(configured));
+///
+/// assert_eq!(Currency::USD.symbol_with(&overrides).to_string(), "US$");
+/// assert_eq!(Currency::RUB.symbol_with(&overrides).symbol, "руб.");
+/// assert_eq!(
+///     Currency::EUR.symbol_with(&overrides).symbol,
+///     Currency::EUR.symbol().symbol
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolOverrides {
+    symbols: HashMap<Currency, SymbolOverride>,
+}
+
+impl SymbolOverrides {
+    /// Creates an empty set of overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the symbol of a currency
+    pub fn insert<S: Into<SymbolOverride>>(&mut self, currency: Currency, symbol: S) -> &mut Self {
+        self.symbols.insert(currency, symbol.into());
+        self
+    }
+
+    /// Removes the override of a currency, returning the overridden symbol
+    pub fn remove(&mut self, currency: Currency) -> Option<SymbolOverride> {
+        self.symbols.remove(&currency)
+    }
+
+    /// Returns the overridden symbol of a currency, `None` if it isn't overridden
+    pub fn get(&self, currency: Currency) -> Option<&SymbolOverride> {
+        self.symbols.get(&currency)
+    }
+}
+
+impl Currency {
+    /// Returns the symbol of the currency, preferring the given overrides
+    ///
+    /// Overridden symbols are borrowed from the registry, all others are
+    /// built from `Currency::symbol` without allocating. See
+    /// `SymbolOverrides` for an example.
+    pub fn symbol_with(self, overrides: &SymbolOverrides) -> Cow<'_, SymbolOverride> {
+        match overrides.get(self) {
+            Some(symbol) => Cow::Borrowed(symbol),
+            None => Cow::Owned(self.symbol().into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Country, CountryCurrencyOverrides, Currency, CurrencySymbol, SymbolOverride,
+        SymbolOverrides,
+    };
+
+    #[test]
+    fn falls_back_to_data() {
+        let overrides = CountryCurrencyOverrides::new();
+        assert_eq!(overrides.from_country(Country::AF), vec![Currency::AFN]);
+        assert_eq!(overrides.from_code("af"), vec![Currency::AFN]);
+        assert_eq!(overrides.from_code("XK"), vec![]);
+        assert_eq!(overrides.regular_currency(Country::AQ), None);
+    }
+
+    #[test]
+    fn overrides_take_precedence() {
+        let mut overrides = CountryCurrencyOverrides::new();
+        overrides.insert(Country::IO, vec![Currency::USD]);
+        overrides.insert_code("xk", vec![Currency::EUR]);
+        assert_eq!(overrides.from_country(Country::IO), vec![Currency::USD]);
+        assert_eq!(overrides.regular_currency(Country::IO), Some(Currency::USD));
+        assert_eq!(overrides.from_code("XK"), vec![Currency::EUR]);
+
+        assert_eq!(overrides.remove(Country::IO), Some(vec![Currency::USD]));
+        assert_eq!(overrides.regular_currency(Country::IO), Some(Currency::GBP));
+    }
+
+    #[test]
+    fn symbol_overrides() {
+        let mut overrides = SymbolOverrides::new();
+        assert_eq!(
+            *Currency::RUB.symbol_with(&overrides),
+            SymbolOverride::from(Currency::RUB.symbol())
+        );
+
+        let rub = SymbolOverride::new(String::from("руб.")).with_subunit("коп.");
+        overrides.insert(Currency::RUB, rub.clone());
+        assert_eq!(*Currency::RUB.symbol_with(&overrides), rub);
+        assert_eq!(overrides.get(Currency::RUB), Some(&rub));
+        assert_eq!(
+            Currency::RUB
+                .symbol_with(&overrides)
+                .subunit_symbol
+                .as_deref(),
+            Some("коп.")
+        );
+
+        overrides.insert(Currency::USD, CurrencySymbol::new("US$", None));
+        assert_eq!(Currency::USD.symbol_with(&overrides).to_string(), "US$");
+
+        assert_eq!(overrides.remove(Currency::RUB), Some(rub));
+        assert_eq!(overrides.get(Currency::RUB), None);
+    }
+}
@@ -0,0 +1,123 @@
+//! Conventions of payment providers which differ from ISO 4217
+//!
+//! Payment APIs expect amounts as integers in the smallest unit they support
+//! for a currency. Their lists of zero-decimal currencies don't always match
+//! `Currency::exponent`, e.g. Stripe treats `MGA` as zero-decimal while
+//! ISO 4217 gives it 2 decimal places.
+
+use crate::Currency;
+
+/// A payment service provider with its own minor unit conventions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Psp {
+    Stripe,
+    PayPal,
+}
+
+impl Currency {
+    /// Returns the number of decimal places a payment provider uses for amounts
+    ///
+    /// This is the exponent to convert integer amounts sent to or received
+    /// from the provider's API. It falls back to `Currency::exponent` for
+    /// currencies the provider doesn't treat differently.
+    ///
+    /// Stripe keeps 2 decimal places for `ISK`, `HUF` and `TWD` in API amounts
+    /// even though `ISK` has none in ISO 4217 and only whole `HUF` and `TWD`
+    /// amounts can be paid out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Currency, Psp};
+    ///
+    /// assert_eq!(Currency::MGA.psp_minor_unit(Psp::Stripe), Some(0));
+    /// assert_eq!(Currency::ISK.psp_minor_unit(Psp::Stripe), Some(2));
+    /// assert_eq!(Currency::HUF.psp_minor_unit(Psp::PayPal), Some(0));
+    /// assert_eq!(Currency::EUR.psp_minor_unit(Psp::PayPal), Some(2));
+    /// ```
+    pub fn psp_minor_unit(self, psp: Psp) -> Option<u16> {
+        match psp {
+            Psp::Stripe if self.stripe_zero_decimal() => Some(0),
+            Psp::Stripe if self.code() == "ISK" => Some(2),
+            Psp::PayPal if self.paypal_zero_decimal() => Some(0),
+            _ => self.exponent(),
+        }
+    }
+
+    /// Returns true if Stripe expects amounts of the currency without decimals
+    ///
+    /// Follows the list of zero-decimal currencies in Stripe's documentation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert!(Currency::JPY.stripe_zero_decimal());
+    /// assert!(Currency::MGA.stripe_zero_decimal());
+    /// assert!(!Currency::ISK.stripe_zero_decimal());
+    /// assert!(!Currency::EUR.stripe_zero_decimal());
+    /// ```
+    pub fn stripe_zero_decimal(self) -> bool {
+        // matched by code as some of these might be filtered out of the enum
+        matches!(
+            self.code(),
+            "BIF"
+                | "CLP"
+                | "DJF"
+                | "GNF"
+                | "JPY"
+                | "KMF"
+                | "KRW"
+                | "MGA"
+                | "PYG"
+                | "RWF"
+                | "UGX"
+                | "VND"
+                | "VUV"
+                | "XAF"
+                | "XOF"
+                | "XPF"
+        )
+    }
+
+    /// Returns true if PayPal doesn't accept decimals for the currency
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert!(Currency::HUF.paypal_zero_decimal());
+    /// assert!(!Currency::KRW.paypal_zero_decimal());
+    /// ```
+    pub fn paypal_zero_decimal(self) -> bool {
+        matches!(self.code(), "HUF" | "JPY" | "TWD")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Psp};
+
+    #[test]
+    fn differs_from_iso() {
+        // zero-decimal for Stripe but not in ISO 4217 and the other way around
+        assert_eq!(Currency::MGA.exponent(), Some(2));
+        assert!(Currency::MGA.stripe_zero_decimal());
+        assert_eq!(Currency::ISK.exponent(), Some(0));
+        assert!(!Currency::ISK.stripe_zero_decimal());
+        assert_eq!(Currency::TWD.exponent(), Some(2));
+        assert!(Currency::TWD.paypal_zero_decimal());
+    }
+
+    #[test]
+    fn psp_minor_unit() {
+        assert_eq!(Currency::JPY.psp_minor_unit(Psp::Stripe), Some(0));
+        assert_eq!(Currency::HUF.psp_minor_unit(Psp::Stripe), Some(2));
+        assert_eq!(Currency::TWD.psp_minor_unit(Psp::PayPal), Some(0));
+        assert_eq!(Currency::KWD.psp_minor_unit(Psp::Stripe), Some(3));
+        assert_eq!(Currency::XAU.psp_minor_unit(Psp::PayPal), None);
+    }
+}
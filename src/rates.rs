@@ -0,0 +1,180 @@
+//! Sharing exchange rate snapshots between threads
+//!
+//! A [`RateSnapshot`] holds the rates of one fetch. [`SharedRateTable`]
+//! publishes the current snapshot to any number of readers and replaces it
+//! atomically, so readers always see a consistent set of rates. With the
+//! `with-arc-swap` feature reads don't take a lock.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use crate::{Currency, Ratio};
+
+/// Exchange rates against a base currency, each with the time it was quoted
+///
+/// A rate is the price of one unit of the base currency in units of the
+/// other currency, as expected by `convert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateSnapshot {
+    base: Currency,
+    rates: HashMap<Currency, (Ratio, SystemTime)>,
+}
+
+impl RateSnapshot {
+    /// Creates an empty snapshot of rates against `base`
+    pub fn new(base: Currency) -> Self {
+        RateSnapshot {
+            base,
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Returns the base currency of the rates
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Sets the rate of `currency` quoted at `as_of`
+    pub fn insert(&mut self, currency: Currency, rate: Ratio, as_of: SystemTime) {
+        self.rates.insert(currency, (rate, as_of));
+    }
+
+    /// Returns the rate of `currency`, the base currency has a rate of 1
+    pub fn rate(&self, currency: Currency) -> Option<Ratio> {
+        if currency == self.base {
+            return Some(Ratio::new(1, 1));
+        }
+        self.rates.get(&currency).map(|(rate, _)| *rate)
+    }
+
+    /// Returns when the rate of `currency` was quoted
+    pub fn as_of(&self, currency: Currency) -> Option<SystemTime> {
+        self.rates.get(&currency).map(|(_, as_of)| *as_of)
+    }
+
+    /// Returns true if the rate of `currency` is missing or older than `max_age` at `now`
+    ///
+    /// Rates quoted after `now` aren't stale.
+    pub fn is_stale(&self, currency: Currency, max_age: Duration, now: SystemTime) -> bool {
+        if currency == self.base {
+            return false;
+        }
+        match self.as_of(currency) {
+            Some(as_of) => now.duration_since(as_of).is_ok_and(|age| age > max_age),
+            None => true,
+        }
+    }
+}
+
+/// The current rate snapshot, shared between threads
+///
+/// Readers get an `Arc` of the snapshot which stays valid and unchanged
+/// while a writer replaces it, so a request can use one snapshot throughout.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::time::SystemTime;
+/// use iso_currency::{Currency, Ratio, RateSnapshot, SharedRateTable};
+///
+/// let table = Arc::new(SharedRateTable::new(RateSnapshot::new(Currency::EUR)));
+///
+/// let before = table.snapshot();
+/// let mut update = RateSnapshot::new(Currency::EUR);
+/// update.insert(Currency::USD, Ratio::new(10850, 10000), SystemTime::now());
+/// table.replace(update);
+///
+/// assert_eq!(before.rate(Currency::USD), None);
+/// assert_eq!(table.snapshot().rate(Currency::USD), Some(Ratio::new(10850, 10000)));
+/// ```
+#[derive(Debug)]
+pub struct SharedRateTable {
+    #[cfg(not(feature = "with-arc-swap"))]
+    current: std::sync::RwLock<Arc<RateSnapshot>>,
+    #[cfg(feature = "with-arc-swap")]
+    current: arc_swap::ArcSwap<RateSnapshot>,
+}
+
+impl SharedRateTable {
+    /// Creates a table publishing `snapshot`
+    pub fn new(snapshot: RateSnapshot) -> Self {
+        SharedRateTable {
+            #[cfg(not(feature = "with-arc-swap"))]
+            current: std::sync::RwLock::new(Arc::new(snapshot)),
+            #[cfg(feature = "with-arc-swap")]
+            current: arc_swap::ArcSwap::from_pointee(snapshot),
+        }
+    }
+
+    /// Returns the current snapshot
+    pub fn snapshot(&self) -> Arc<RateSnapshot> {
+        #[cfg(not(feature = "with-arc-swap"))]
+        return Arc::clone(&self.current.read().unwrap_or_else(|e| e.into_inner()));
+        #[cfg(feature = "with-arc-swap")]
+        return self.current.load_full();
+    }
+
+    /// Replaces the current snapshot, returning the previous one
+    pub fn replace(&self, snapshot: RateSnapshot) -> Arc<RateSnapshot> {
+        let snapshot = Arc::new(snapshot);
+        #[cfg(not(feature = "with-arc-swap"))]
+        return std::mem::replace(
+            &mut *self.current.write().unwrap_or_else(|e| e.into_inner()),
+            snapshot,
+        );
+        #[cfg(feature = "with-arc-swap")]
+        return self.current.swap(snapshot);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, SystemTime};
+
+    use crate::{Currency, RateSnapshot, Ratio, SharedRateTable};
+
+    #[test]
+    fn staleness() {
+        let quoted = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut snapshot = RateSnapshot::new(Currency::USD);
+        snapshot.insert(Currency::JPY, Ratio::new(150, 1), quoted);
+
+        let minute = Duration::from_secs(60);
+        assert!(!snapshot.is_stale(Currency::JPY, minute, quoted + minute));
+        assert!(snapshot.is_stale(Currency::JPY, minute, quoted + minute * 2));
+        assert!(!snapshot.is_stale(Currency::JPY, minute, quoted - minute));
+        assert!(snapshot.is_stale(Currency::EUR, minute, quoted));
+        assert!(!snapshot.is_stale(Currency::USD, minute, quoted));
+        assert_eq!(snapshot.rate(Currency::USD), Some(Ratio::new(1, 1)));
+        assert_eq!(snapshot.as_of(Currency::JPY), Some(quoted));
+    }
+
+    #[test]
+    fn replace_across_threads() {
+        let table = Arc::new(SharedRateTable::new(RateSnapshot::new(Currency::EUR)));
+        let writer = {
+            let table = Arc::clone(&table);
+            thread::spawn(move || {
+                for i in 1..=100 {
+                    let mut snapshot = RateSnapshot::new(Currency::EUR);
+                    snapshot.insert(Currency::USD, Ratio::new(i, 100), SystemTime::now());
+                    snapshot.insert(Currency::GBP, Ratio::new(i, 100), SystemTime::now());
+                    table.replace(snapshot);
+                }
+            })
+        };
+        for _ in 0..100 {
+            let snapshot = table.snapshot();
+            assert_eq!(snapshot.rate(Currency::USD), snapshot.rate(Currency::GBP));
+        }
+        writer.join().unwrap();
+        assert_eq!(
+            table.snapshot().rate(Currency::USD),
+            Some(Ratio::new(100, 100))
+        );
+    }
+}
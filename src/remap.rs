@@ -0,0 +1,172 @@
+//! Lenient deserialization of currency codes through a remap table
+//!
+//! Older feeds keep sending codes which are no longer part of ISO 4217 (or
+//! were never part of this crate's data). A [`CodeRemap`] lets the caller
+//! decide which of those codes should be accepted and what they map to.
+//!
+//! # Example
+//!
+//! ```
+//! use iso_currency::remap::CodeRemap;
+//! use iso_currency::Currency;
+//! use serde::de::DeserializeSeed;
+//!
+//! let mut remap = CodeRemap::new().follow_superseded(true);
+//! remap.insert("LTL", Currency::EUR);
+//!
+//! let mut de = serde_json::Deserializer::from_str("\"LTL\"");
+//! assert_eq!(remap.deserialize(&mut de).unwrap(), Currency::EUR);
+//!
+//! let mut de = serde_json::Deserializer::from_str("\"HRK\"");
+//! assert_eq!(remap.deserialize(&mut de).unwrap(), Currency::EUR);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+
+use crate::Currency;
+
+/// A table of extra currency codes accepted during deserialization
+#[derive(Debug, Clone, Default)]
+pub struct CodeRemap {
+    codes: HashMap<String, Currency>,
+    follow_superseded: bool,
+}
+
+impl CodeRemap {
+    /// Creates an empty remap table which accepts only known codes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace superseded currencies with their successor
+    ///
+    /// When enabled a known but superseded code such as `HRK` resolves to
+    /// `Currency::latest` instead of the code itself.
+    pub fn follow_superseded(mut self, follow: bool) -> Self {
+        self.follow_superseded = follow;
+        self
+    }
+
+    /// Accept `code` and map it to `currency`
+    ///
+    /// Entries in the table take precedence over the crate's own data.
+    pub fn insert(&mut self, code: &str, currency: Currency) -> &mut Self {
+        self.codes.insert(code.to_owned(), currency);
+        self
+    }
+
+    /// Resolves a code through the table, falling back to `Currency::from_code`
+    ///
+    /// Only well-formed codes (three uppercase ASCII letters) are resolved.
+    pub fn resolve(&self, code: &str) -> Option<Currency> {
+        if !Currency::is_wellformed_code(code) {
+            return None;
+        }
+        if let Some(currency) = self.codes.get(code) {
+            return Some(*currency);
+        }
+        Currency::from_code(code).map(|c| match self.follow_superseded {
+            true => c.latest(),
+            false => c,
+        })
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for &CodeRemap {
+    type Value = Currency;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(RemapVisitor(self))
+    }
+}
+
+struct RemapVisitor<'a>(&'a CodeRemap);
+
+impl<'de> Visitor<'de> for RemapVisitor<'_> {
+    type Value = Currency;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an ISO 4217 currency code or a remapped code")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.0
+            .resolve(v)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Deserializes a currency code and replaces superseded currencies with their successor
+///
+/// Meant to be used as `#[serde(deserialize_with = "iso_currency::remap::deserialize_latest")]`.
+pub fn deserialize_latest<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    CodeRemap::new()
+        .follow_superseded(true)
+        .deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodeRemap;
+    use crate::Currency;
+    use serde::de::DeserializeSeed;
+    use serde::Deserialize;
+
+    fn parse(remap: &CodeRemap, json: &str) -> Result<Currency, serde_json::Error> {
+        remap.deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn known_codes_pass_through() {
+        let remap = CodeRemap::new();
+        assert_eq!(parse(&remap, "\"EUR\"").unwrap(), Currency::EUR);
+        assert_eq!(parse(&remap, "\"HRK\"").unwrap(), Currency::HRK);
+    }
+
+    #[test]
+    fn unknown_codes_are_rejected_unless_remapped() {
+        let mut remap = CodeRemap::new();
+        assert!(parse(&remap, "\"LVL\"").is_err());
+        remap.insert("LVL", Currency::EUR);
+        assert_eq!(parse(&remap, "\"LVL\"").unwrap(), Currency::EUR);
+    }
+
+    #[test]
+    fn malformed_codes_are_rejected() {
+        let mut remap = CodeRemap::new();
+        remap.insert("eur", Currency::EUR);
+        assert!(parse(&remap, "\"eur\"").is_err());
+        assert!(parse(&remap, "\"EURO\"").is_err());
+        assert!(parse(&remap, "978").is_err());
+    }
+
+    #[test]
+    fn follows_superseded() {
+        let remap = CodeRemap::new().follow_superseded(true);
+        assert_eq!(parse(&remap, "\"VES\"").unwrap(), Currency::VED);
+    }
+
+    #[test]
+    fn deserialize_latest() {
+        #[derive(Deserialize)]
+        struct Payment {
+            #[serde(deserialize_with = "super::deserialize_latest")]
+            currency: Currency,
+        }
+
+        let payment: Payment = serde_json::from_str("{\"currency\": \"SLL\"}").unwrap();
+        assert_eq!(payment.currency, Currency::SLE);
+    }
+}
@@ -0,0 +1,85 @@
+//! Consistency check of the JSON schema and the serde representation
+//!
+//! Crates deriving their own schemas from `Currency` can call [`check`] in
+//! their tests to make sure that the schema generated by `schemars` accepts
+//! exactly the values `serde` accepts, including in builds filtered with
+//! `ISO_CURRENCY_COUNTRIES`.
+
+use schemars::schema::{Schema, SchemaObject};
+use serde::de::value::{Error as ValueError, StrDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+use crate::Currency;
+
+/// A difference between the JSON schema and the serde representation
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SchemaMismatch {
+    /// The schema doesn't list the accepted values
+    #[error("the schema of Currency isn't an enum of strings")]
+    NotAnEnum,
+    /// A value allowed by the schema doesn't deserialize
+    #[error("{0:?} is allowed by the schema but doesn't deserialize")]
+    Invalid(String),
+    /// More than one value of the schema deserializes to the same currency
+    #[error("{0:?} is listed more than once in the schema")]
+    Duplicate(Currency),
+    /// A currency isn't allowed by the schema
+    #[error("{0:?} is missing from the schema")]
+    Missing(Currency),
+}
+
+// the documented variants are listed as `oneOf` single value enums
+fn schema_values(schema: &SchemaObject) -> Result<Vec<&str>, SchemaMismatch> {
+    if let Some(values) = &schema.enum_values {
+        return values
+            .iter()
+            .map(|value| value.as_str().ok_or(SchemaMismatch::NotAnEnum))
+            .collect();
+    }
+    let variants = schema
+        .subschemas
+        .as_ref()
+        .and_then(|subschemas| subschemas.one_of.as_ref())
+        .ok_or(SchemaMismatch::NotAnEnum)?;
+    let mut values = Vec::new();
+    for variant in variants {
+        match variant {
+            Schema::Object(object) => values.extend(schema_values(object)?),
+            Schema::Bool(_) => return Err(SchemaMismatch::NotAnEnum),
+        }
+    }
+    Ok(values)
+}
+
+/// Checks that every value of the schema deserializes and that every currency is in the schema
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(iso_currency::schema_check::check(), Ok(()));
+/// ```
+pub fn check() -> Result<(), SchemaMismatch> {
+    let mut seen = [false; Currency::COUNT];
+    for value in schema_values(&schemars::schema_for!(Currency).schema)? {
+        let deserializer: StrDeserializer<ValueError> = value.into_deserializer();
+        let currency = Currency::deserialize(deserializer)
+            .map_err(|_| SchemaMismatch::Invalid(value.to_owned()))?;
+        if std::mem::replace(&mut seen[currency.dense_index()], true) {
+            return Err(SchemaMismatch::Duplicate(currency));
+        }
+    }
+
+    match Currency::VARIANTS.iter().find(|c| !seen[c.dense_index()]) {
+        Some(currency) => Err(SchemaMismatch::Missing(*currency)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn schema_matches_serde() {
+        assert_eq!(super::check(), Ok(()));
+    }
+}
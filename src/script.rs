@@ -0,0 +1,59 @@
+//! Writing systems of script specific currency symbols
+
+/// A writing system, as used by `Currency::symbol_for_script`
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Script;
+///
+/// assert_eq!(Script::Cyrillic.code(), "Cyrl");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Script {
+    /// Arabic script (`Arab`), e.g. `ر.س` for the Saudi riyal
+    Arabic,
+    /// Cyrillic script (`Cyrl`), e.g. `дин` for the Serbian dinar
+    Cyrillic,
+    /// Latin script (`Latn`), e.g. `SR` for the Saudi riyal
+    Latin,
+}
+
+impl Script {
+    /// Returns the ISO 15924 code of the script, e.g. `Latn`
+    pub fn code(self) -> &'static str {
+        match self {
+            Script::Arabic => "Arab",
+            Script::Cyrillic => "Cyrl",
+            Script::Latin => "Latn",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, Script};
+
+    #[test]
+    fn symbol_for_script() {
+        assert_eq!(
+            Currency::BGN.symbol_for_script(Script::Cyrillic),
+            Some("лв.")
+        );
+        assert_eq!(Currency::UZS.symbol_for_script(Script::Latin), Some("soʻm"));
+        assert_eq!(Currency::SAR.symbol_for_script(Script::Arabic), Some("ر.س"));
+        assert_eq!(Currency::SAR.symbol_for_script(Script::Latin), Some("SR"));
+        assert_eq!(Currency::RSD.symbol_for_script(Script::Arabic), None);
+        // the regular symbol is one of the script specific ones, unless it's the generic sign
+        for currency in Currency::VARIANTS {
+            let scripts = [Script::Arabic, Script::Cyrillic, Script::Latin];
+            let symbols: Vec<&str> = scripts
+                .iter()
+                .filter_map(|script| currency.symbol_for_script(*script))
+                .collect();
+            let symbol = currency.symbol().symbol;
+            assert!(symbols.is_empty() || symbol == "¤" || symbols.contains(&symbol));
+        }
+    }
+}
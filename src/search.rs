@@ -0,0 +1,124 @@
+//! Searching currencies by partial or mistyped input
+
+use crate::{Currency, PREFIX_INDEX};
+
+// edit distance between two short ASCII strings
+fn levenshtein(a: &[u8], b: &[u8]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+impl Currency {
+    /// Returns up to `limit` currencies whose code or name starts with `prefix`
+    ///
+    /// The search is case insensitive and matches the start of every word in
+    /// the name, so `"dollar"` finds the United States dollar. It runs a
+    /// binary search over a table sorted when the crate is built. Results are
+    /// sorted by the matched text and every currency is returned only once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// assert_eq!(Currency::prefix_search("eu", 2), vec![Currency::EUR, Currency::CHE]);
+    /// assert_eq!(Currency::prefix_search("Swiss", 1), vec![Currency::CHF]);
+    /// assert!(Currency::prefix_search("dollar", 100).contains(&Currency::USD));
+    /// ```
+    pub fn prefix_search(prefix: &str, limit: usize) -> Vec<Currency> {
+        let prefix = prefix.trim().to_lowercase();
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        let start = PREFIX_INDEX.partition_point(|(key, _)| *key < prefix.as_str());
+
+        let mut results = Vec::new();
+        for (_, currency) in PREFIX_INDEX[start..]
+            .iter()
+            .take_while(|(key, _)| key.starts_with(&prefix))
+        {
+            if results.len() == limit {
+                break;
+            }
+            if !results.contains(currency) {
+                results.push(*currency);
+            }
+        }
+        results
+    }
+
+    /// Returns the currencies whose code is at most `max_distance` edits away from `code`
+    ///
+    /// The distance is the Levenshtein distance, ignoring case. Results are
+    /// sorted by distance, so an exact match comes first, and then by code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::Currency;
+    ///
+    /// let suggestions = Currency::suggest("EUT", 1);
+    /// assert_eq!(suggestions[0], Currency::EUR);
+    /// assert!(!suggestions.contains(&Currency::ETB));
+    /// assert_eq!(Currency::suggest("usd", 0), vec![Currency::USD]);
+    /// assert!(Currency::suggest("QQQ", 1).is_empty());
+    /// ```
+    pub fn suggest(code: &str, max_distance: u8) -> Vec<Currency> {
+        let code = code.trim().to_ascii_uppercase();
+        let mut matches: Vec<(usize, Currency)> = Currency::VARIANTS
+            .iter()
+            .map(|c| (levenshtein(code.as_bytes(), c.code().as_bytes()), *c))
+            .filter(|(distance, _)| *distance <= usize::from(max_distance))
+            .collect();
+        matches.sort_by_key(|(distance, c)| (*distance, c.code()));
+        matches.into_iter().map(|(_, c)| c).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::levenshtein;
+    use crate::Currency;
+
+    #[test]
+    fn prefix_search() {
+        assert_eq!(Currency::prefix_search("SEK", 5), vec![Currency::SEK]);
+        assert_eq!(
+            Currency::prefix_search("bolívar", 5),
+            vec![Currency::VED, Currency::VES]
+        );
+        assert!(Currency::prefix_search("franc", 100).contains(&Currency::CHF));
+        assert_eq!(Currency::prefix_search("dollar", 3).len(), 3);
+        assert!(Currency::prefix_search("dollar", 0).is_empty());
+        assert!(Currency::prefix_search("  ", 10).is_empty());
+        assert!(Currency::prefix_search("zzz", 10).is_empty());
+    }
+
+    #[test]
+    fn levenshtein_distance() {
+        assert_eq!(levenshtein(b"EUR", b"EUR"), 0);
+        assert_eq!(levenshtein(b"EUR", b"EUT"), 1);
+        assert_eq!(levenshtein(b"EUR", b"UER"), 2);
+        assert_eq!(levenshtein(b"EURO", b"EUR"), 1);
+        assert_eq!(levenshtein(b"", b"EUR"), 3);
+    }
+
+    #[test]
+    fn suggest() {
+        assert!(!Currency::suggest("GPB", 1).contains(&Currency::GBP));
+        assert!(Currency::suggest("GPB", 2).contains(&Currency::GBP));
+        assert_eq!(Currency::suggest("EURO", 1), vec![Currency::EUR]);
+        let suggestions = Currency::suggest("US", 1);
+        assert!(suggestions
+            .iter()
+            .all(|c| c.code().contains('U') || c.code().contains('S')));
+    }
+}
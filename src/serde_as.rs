@@ -0,0 +1,205 @@
+//! Adapters for `serde_with`'s `#[serde_as]`
+//!
+//! Each adapter picks a different representation of a `Currency`. They work
+//! anywhere `serde_with` accepts an adapter, e.g. `Option<CodeLower>` or
+//! `Vec<Numeric>`.
+//!
+//! # Example
+//!
+//! ```
+//! use iso_currency::serde_as::{CodeLower, Numeric};
+//! use iso_currency::Currency;
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Prices {
+//!     #[serde_as(as = "CodeLower")]
+//!     base: Currency,
+//!     #[serde_as(as = "Vec<Numeric>")]
+//!     quoted: Vec<Currency>,
+//! }
+//!
+//! let prices: Prices = serde_json::from_str(r#"{"base": "eur", "quoted": [840, 392]}"#).unwrap();
+//! assert_eq!(prices.base, Currency::EUR);
+//! assert_eq!(prices.quoted, vec![Currency::USD, Currency::JPY]);
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::Serializer;
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::Currency;
+
+/// Represents a currency as its uppercase code, e.g. `"EUR"`
+///
+/// This is the same representation as the derived implementations.
+pub struct CodeUpper;
+
+/// Represents a currency as its lowercase code, e.g. `"eur"`
+pub struct CodeLower;
+
+/// Represents a currency as its numeric code, e.g. `978`
+pub struct Numeric;
+
+/// Represents a currency as its English name, e.g. `"Euro"`
+///
+/// If several currencies share a name, deserialization returns the one
+/// which isn't superseded.
+pub struct NameString;
+
+// accepts a string and resolves it with a function
+struct StrVisitor(&'static str, fn(&str) -> Option<Currency>);
+
+impl Visitor<'_> for StrVisitor {
+    type Value = Currency;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        (self.1)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+impl SerializeAs<Currency> for CodeUpper {
+    fn serialize_as<S>(source: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(source.code())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Currency> for CodeUpper {
+    fn deserialize_as<D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(
+            "an uppercase ISO 4217 currency code",
+            Currency::from_code,
+        ))
+    }
+}
+
+impl SerializeAs<Currency> for CodeLower {
+    fn serialize_as<S>(source: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::serde_helpers::lowercase::serialize(source, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Currency> for CodeLower {
+    fn deserialize_as<D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde_helpers::lowercase::deserialize(deserializer)
+    }
+}
+
+impl SerializeAs<Currency> for Numeric {
+    fn serialize_as<S>(source: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u16(source.numeric())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Currency> for Numeric {
+    fn deserialize_as<D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let numeric = <u16 as serde::Deserialize>::deserialize(deserializer)?;
+        Currency::from_numeric(numeric).ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Unsigned(u64::from(numeric)),
+                &"an ISO 4217 numeric currency code",
+            )
+        })
+    }
+}
+
+impl SerializeAs<Currency> for NameString {
+    fn serialize_as<S>(source: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(source.name())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Currency> for NameString {
+    fn deserialize_as<D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(
+            "the English name of an ISO 4217 currency",
+            Currency::from_name,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeUpper, NameString, Numeric};
+    use crate::Currency;
+    use serde::{Deserialize, Serialize};
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde_as(as = "CodeUpper")]
+        code: Currency,
+        #[serde_as(as = "Option<Numeric>")]
+        numeric: Option<Currency>,
+        #[serde_as(as = "NameString")]
+        name: Currency,
+    }
+
+    #[test]
+    fn round_trip() {
+        let record = Record {
+            code: Currency::SEK,
+            numeric: Some(Currency::DZD),
+            name: Currency::SLE,
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"code":"SEK","numeric":12,"name":"Sierra Leonean leone"}"#
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn rejects_unknown() {
+        let parse = |json| serde_json::from_str::<Record>(json);
+        assert!(parse(r#"{"code":"sek","numeric":12,"name":"Euro"}"#).is_err());
+        assert!(parse(r#"{"code":"SEK","numeric":1,"name":"Euro"}"#).is_err());
+        assert!(parse(r#"{"code":"SEK","numeric":null,"name":"Euros"}"#).is_err());
+    }
+
+    #[test]
+    fn name_prefers_current() {
+        assert_eq!(Currency::from_name("Euro"), Some(Currency::EUR));
+        assert_eq!(
+            Currency::from_name("Venezuelan bolívar soberano"),
+            Some(Currency::VED)
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Helpers for `#[serde(with = "...")]` attributes
+//!
+//! The derived implementations use the uppercase ISO 4217 code. Use these
+//! modules for APIs which represent currencies differently, and the
+//! `default_*` functions for `#[serde(default = "...")]` attributes.
+
+use crate::Currency;
+
+/// Returns the currency with the numeric code `NUMERIC`, for `#[serde(default = "...")]`
+///
+/// Panics if no currency has the numeric code, e.g. because it was left out
+/// with `ISO_CURRENCY_COUNTRIES`.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Currency;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "iso_currency::serde_helpers::default_currency::<756>")]
+///     currency: Currency,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.currency, Currency::CHF);
+/// ```
+pub fn default_currency<const NUMERIC: u16>() -> Currency {
+    Currency::from_numeric(NUMERIC)
+        .unwrap_or_else(|| panic!("no currency with the numeric code {:03}", NUMERIC))
+}
+
+/// Returns `Currency::EUR`, for `#[serde(default = "iso_currency::serde_helpers::default_eur")]`
+pub fn default_eur() -> Currency {
+    default_currency::<978>()
+}
+
+/// Returns `Currency::USD`, for `#[serde(default = "iso_currency::serde_helpers::default_usd")]`
+pub fn default_usd() -> Currency {
+    default_currency::<840>()
+}
+
+/// Returns `Currency::GBP`, for `#[serde(default = "iso_currency::serde_helpers::default_gbp")]`
+pub fn default_gbp() -> Currency {
+    default_currency::<826>()
+}
+
+/// (De)serializes a currency as its lowercase code, e.g. `"eur"`
+///
+/// This is the representation used by Stripe. Deserialization only accepts
+/// lowercase codes.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::Currency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Charge {
+///     amount: i64,
+///     #[serde(with = "iso_currency::serde_helpers::lowercase")]
+///     currency: Currency,
+/// }
+///
+/// let charge: Charge = serde_json::from_str(r#"{"amount": 500, "currency": "eur"}"#).unwrap();
+/// assert_eq!(charge.currency, Currency::EUR);
+/// assert_eq!(
+///     serde_json::to_string(&charge).unwrap(),
+///     r#"{"amount":500,"currency":"eur"}"#
+/// );
+/// ```
+pub mod lowercase {
+    use std::fmt;
+
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::Serializer;
+
+    use crate::Currency;
+
+    pub fn serialize<S>(currency: &Currency, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(currency.code_lowercase())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Currency, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LowercaseVisitor)
+    }
+
+    struct LowercaseVisitor;
+
+    impl Visitor<'_> for LowercaseVisitor {
+        type Value = Currency;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a lowercase ISO 4217 currency code")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.bytes().any(|b| !b.is_ascii_lowercase()) {
+                return Err(E::invalid_value(de::Unexpected::Str(v), &self));
+            }
+            Currency::from_code(&v.to_ascii_uppercase())
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Payload {
+        #[serde(with = "super::lowercase")]
+        currency: Currency,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[serde(default = "super::default_eur")]
+        currency: Currency,
+        #[serde(default = "super::default_currency::<392>")]
+        fallback: Currency,
+    }
+
+    #[test]
+    fn defaults() {
+        let config: Config = serde_json::from_str(r#"{"currency": "USD"}"#).unwrap();
+        assert_eq!(config.currency, Currency::USD);
+        assert_eq!(config.fallback, Currency::JPY);
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.currency, Currency::EUR);
+        assert_eq!(super::default_usd(), Currency::USD);
+        assert_eq!(super::default_gbp(), Currency::GBP);
+    }
+
+    #[test]
+    fn lowercase() {
+        let parse = |json| serde_json::from_str::<Payload>(json).map(|p| p.currency);
+        assert_eq!(parse(r#"{"currency": "jpy"}"#).unwrap(), Currency::JPY);
+        assert!(parse(r#"{"currency": "JPY"}"#).is_err());
+        assert!(parse(r#"{"currency": "abc"}"#).is_err());
+    }
+}
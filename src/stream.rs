@@ -0,0 +1,140 @@
+//! Validating large lists of currency codes line by line
+
+use std::io::BufRead;
+use std::sync::Arc;
+
+use crate::Currency;
+
+/// Error returned by `validate_stream` for a single line
+///
+/// I/O errors are shared behind an `Arc` so the error can be cloned, and
+/// compare equal when the line and the `io::ErrorKind` match.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LineError {
+    /// The line couldn't be read
+    #[error("failed to read line {line}")]
+    Io {
+        line: usize,
+        #[source]
+        source: Arc<std::io::Error>,
+    },
+    /// The line doesn't contain a valid currency code
+    #[error("line {line}: {value:?} is not a valid ISO 4217 currency code")]
+    Invalid { line: usize, value: String },
+}
+
+impl LineError {
+    /// Returns the line number of the error, starting at 1
+    pub fn line(&self) -> usize {
+        match self {
+            LineError::Io { line, .. } | LineError::Invalid { line, .. } => *line,
+        }
+    }
+}
+
+impl PartialEq for LineError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                LineError::Io { line, source },
+                LineError::Io {
+                    line: other_line,
+                    source: other_source,
+                },
+            ) => line == other_line && source.kind() == other_source.kind(),
+            (
+                LineError::Invalid { line, value },
+                LineError::Invalid {
+                    line: other_line,
+                    value: other_value,
+                },
+            ) => line == other_line && value == other_value,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for LineError {}
+
+/// Validates a newline delimited list of currency codes
+///
+/// Every line holds one code, optionally in double quotes as in NDJSON or
+/// single column CSV files, and surrounded by whitespace. Blank lines are
+/// skipped. The input is read lazily line by line, so arbitrarily large
+/// files can be checked without loading them into memory.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, LineError};
+///
+/// let input = "EUR\n\"USD\"\n\nEURO\n";
+/// let results: Vec<_> = iso_currency::validate_stream(input.as_bytes()).collect();
+/// assert_eq!(results.len(), 3);
+/// assert_eq!(results[0].as_ref().unwrap(), &Currency::EUR);
+/// assert_eq!(results[1].as_ref().unwrap(), &Currency::USD);
+/// assert_eq!(results[2].as_ref().unwrap_err().line(), 4);
+/// ```
+pub fn validate_stream<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Currency, LineError>> {
+    reader.lines().enumerate().filter_map(|(i, line)| {
+        let line_number = i + 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(source) => {
+                return Some(Err(LineError::Io {
+                    line: line_number,
+                    source: Arc::new(source),
+                }))
+            }
+        };
+        let value = line.trim();
+        if value.is_empty() {
+            return None;
+        }
+        let code = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        Some(Currency::from_code(code).ok_or_else(|| LineError::Invalid {
+            line: line_number,
+            value: value.to_owned(),
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{validate_stream, Currency, LineError};
+
+    #[test]
+    fn validate_stream_lines() {
+        let input = "  CHF \r\n\"\"\n\"JPY\n\r\n\"BGN\"";
+        let results: Vec<Result<Currency, LineError>> = validate_stream(input.as_bytes()).collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(*results[0].as_ref().unwrap(), Currency::CHF);
+        assert!(matches!(
+            &results[1],
+            Err(LineError::Invalid { line: 2, value }) if value == "\"\""
+        ));
+        assert!(matches!(
+            &results[2],
+            Err(LineError::Invalid { line: 3, .. })
+        ));
+        assert_eq!(*results[3].as_ref().unwrap(), Currency::BGN);
+        assert_eq!(
+            results[2].as_ref().unwrap_err().to_string(),
+            "line 3: \"\\\"JPY\" is not a valid ISO 4217 currency code"
+        );
+    }
+
+    #[test]
+    fn validate_stream_io_error() {
+        let input: &[u8] = &[b'E', b'U', b'R', b'\n', 0xff, b'\n'];
+        let results: Vec<Result<Currency, LineError>> = validate_stream(input).collect();
+        assert!(matches!(&results[1], Err(LineError::Io { line: 2, .. })));
+
+        let error = results[1].clone().unwrap_err();
+        assert_eq!(error, results[1].clone().unwrap_err());
+        assert_eq!(crate::Error::from(error.clone()), crate::Error::Line(error));
+    }
+}
@@ -0,0 +1,133 @@
+//! Parsing of currency symbols and amounts written with a symbol
+
+use crate::{CurrencySymbol, SYMBOLS};
+
+/// Error returned when parsing an unknown currency symbol
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("not a known currency symbol")]
+pub struct ParseSymbolError;
+
+impl std::str::FromStr for CurrencySymbol {
+    type Err = ParseSymbolError;
+
+    /// Parses one of the main symbols of the known currencies
+    ///
+    /// Symbols are usually shared by several currencies, so the parsed
+    /// symbol never carries a subunit symbol. Use `symbol_collisions` to
+    /// find the currencies using it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::CurrencySymbol;
+    ///
+    /// let symbol: CurrencySymbol = "€".parse().unwrap();
+    /// assert_eq!(symbol, CurrencySymbol::new("€", None));
+    /// assert!("EUR".parse::<CurrencySymbol>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SYMBOLS
+            .binary_search(&s)
+            .map(|i| CurrencySymbol::new(SYMBOLS[i], None))
+            .map_err(|_| ParseSymbolError)
+    }
+}
+
+// characters which can be part of a written amount besides spaces
+fn is_amount_char(c: char) -> bool {
+    c.is_ascii_digit() || matches!(c, '.' | ',' | '\'' | '-' | '+')
+}
+
+// length of the amount at the start of `s`, spaces (used for grouping) are
+// allowed as long as a digit follows them
+fn amount_len(s: &str) -> usize {
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let continues = match c {
+            ' ' | '\u{a0}' | '\u{202f}' => {
+                matches!(chars.peek(), Some((_, next)) if next.is_ascii_digit())
+            }
+            c => is_amount_char(c),
+        };
+        if !continues {
+            return i;
+        }
+    }
+    s.len()
+}
+
+impl CurrencySymbol {
+    /// Splits an amount written with a symbol into the symbol and the amount
+    ///
+    /// The symbol can come before or after the amount, with or without
+    /// whitespace in between. The amount is returned as written, so
+    /// decimal and grouping separators are left for the caller to interpret.
+    /// The symbol isn't checked, parse it with `str::parse` if needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::CurrencySymbol;
+    ///
+    /// assert_eq!(CurrencySymbol::split_amount("€10.99"), Some(("€", "10.99")));
+    /// assert_eq!(CurrencySymbol::split_amount("10,99 €"), Some(("€", "10,99")));
+    /// assert_eq!(CurrencySymbol::split_amount("kr 1 000"), Some(("kr", "1 000")));
+    /// assert_eq!(CurrencySymbol::split_amount("10.99"), None);
+    /// ```
+    pub fn split_amount(input: &str) -> Option<(&str, &str)> {
+        let input = input.trim();
+        let starts_with_amount = input.chars().next().is_some_and(is_amount_char);
+        let (symbol, amount) = if starts_with_amount {
+            let len = amount_len(input);
+            (input[len..].trim_start(), &input[..len])
+        } else {
+            let start = input.find(|c: char| c.is_ascii_digit() || c == '-' || c == '+')?;
+            let amount = &input[start..];
+            if amount_len(amount) != amount.len() {
+                return None;
+            }
+            (input[..start].trim_end(), amount)
+        };
+
+        let valid = !symbol.is_empty()
+            && !symbol.contains(|c: char| c.is_ascii_digit())
+            && amount.contains(|c: char| c.is_ascii_digit());
+        match valid {
+            true => Some((symbol, amount)),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseSymbolError;
+    use crate::{Currency, CurrencySymbol};
+
+    #[test]
+    fn from_str() {
+        assert_eq!("$".parse(), Ok(CurrencySymbol::new("$", None)));
+        assert_eq!(
+            "Kč".parse::<CurrencySymbol>().map(|s| s.symbol),
+            Ok(Currency::CZK.symbol().symbol)
+        );
+        assert_eq!("".parse::<CurrencySymbol>(), Err(ParseSymbolError));
+        assert_eq!("€€".parse::<CurrencySymbol>(), Err(ParseSymbolError));
+    }
+
+    #[test]
+    fn split_amount() {
+        let split = CurrencySymbol::split_amount;
+        assert_eq!(split("  $ 5 "), Some(("$", "5")));
+        assert_eq!(split("-12.50€"), Some(("€", "-12.50")));
+        assert_eq!(
+            split("1\u{a0}234,56\u{a0}zł"),
+            Some(("zł", "1\u{a0}234,56"))
+        );
+        assert_eq!(split("CHF 1'000.00"), Some(("CHF", "1'000.00")));
+        assert_eq!(split("€"), None);
+        assert_eq!(split("€10 €5"), None);
+        assert_eq!(split("10 € 5"), None);
+        assert_eq!(split(""), None);
+    }
+}
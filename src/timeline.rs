@@ -0,0 +1,193 @@
+//! Currencies used by a country over time
+
+use crate::{country_timeline, timeline_countries, Country, Currency, Date, DateAdapter};
+
+/// A period during which a country used a currency
+///
+/// Both `start` and `end` are inclusive, an `end` of `None` means the
+/// currency is still in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyPeriod {
+    pub start: Date,
+    pub end: Option<Date>,
+    pub currency: Currency,
+}
+
+impl CurrencyPeriod {
+    /// Returns true if the given date falls within the period
+    ///
+    /// Dates which can't be converted to `Date` are never contained.
+    pub fn contains<D: DateAdapter>(&self, date: D) -> bool {
+        let date: Date = match date.to_date() {
+            Ok(date) => date,
+            Err(_) => return false,
+        };
+        match self.end {
+            Some(end) => self.start <= date && date <= end,
+            None => self.start <= date,
+        }
+    }
+}
+
+/// The currencies a country has used over time
+///
+/// The data is taken from the `timeline.tsv` table and only covers countries
+/// which changed their currency. Periods are sorted by their start date.
+/// Currencies which predate this crate's data (e.g. the Lithuanian litas)
+/// are not part of the timeline, so the first period may start later than
+/// the country's monetary history.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Country, Currency, CurrencyTimeline, Date};
+///
+/// let timeline = CurrencyTimeline::for_country(Country::HR);
+/// assert_eq!(timeline.periods().len(), 2);
+/// assert_eq!(timeline.currency_at(Date::new(2020, 6, 1)), Some(Currency::HRK));
+/// assert_eq!(timeline.currency_at(Date::new(2023, 6, 1)), Some(Currency::EUR));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyTimeline {
+    country: Country,
+    periods: &'static [CurrencyPeriod],
+}
+
+impl CurrencyTimeline {
+    /// Returns the currency timeline of a country
+    ///
+    /// Countries without any currency changes in the data have an empty
+    /// timeline, use `Currency::from_country` for those.
+    pub fn for_country(country: Country) -> Self {
+        CurrencyTimeline {
+            country,
+            periods: country_timeline(country),
+        }
+    }
+
+    /// Returns the country of the timeline
+    pub fn country(&self) -> Country {
+        self.country
+    }
+
+    /// Returns the periods of the timeline sorted by their start date
+    pub fn periods(&self) -> &'static [CurrencyPeriod] {
+        self.periods
+    }
+
+    /// Returns the currency used on the given date
+    ///
+    /// The date can be a `Date` or any other type implementing `DateAdapter`.
+    pub fn currency_at<D: DateAdapter>(&self, date: D) -> Option<Currency> {
+        let date = date.to_date().ok()?;
+        self.periods
+            .iter()
+            .find(|p| p.contains(date))
+            .map(|p| p.currency)
+    }
+}
+
+impl Currency {
+    /// Returns the countries which used the currency at any time
+    ///
+    /// This extends `Currency::used_by` with the countries which used the
+    /// currency according to the `CurrencyTimeline` data, so transactions in a
+    /// withdrawn currency can still be attributed to a territory. The list
+    /// is sorted like the one of `Currency::used_by`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iso_currency::{Country, Currency};
+    ///
+    /// assert_eq!(Currency::HRK.used_by_historically(), vec![Country::HR]);
+    /// assert!(Currency::EUR.used_by_historically().contains(&Country::HR));
+    /// ```
+    pub fn used_by_historically(self) -> Vec<Country> {
+        let mut countries = self.used_by();
+        for country in timeline_countries(self) {
+            if !countries.contains(country) {
+                countries.push(*country);
+            }
+        }
+        countries.sort();
+        countries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Country, Currency, CurrencyPeriod, CurrencyTimeline, Date};
+
+    #[test]
+    fn periods() {
+        assert_eq!(
+            CurrencyTimeline::for_country(Country::VE).periods(),
+            &[
+                CurrencyPeriod {
+                    start: Date::new(2018, 8, 20),
+                    end: Some(Date::new(2021, 9, 30)),
+                    currency: Currency::VES,
+                },
+                CurrencyPeriod {
+                    start: Date::new(2021, 10, 1),
+                    end: None,
+                    currency: Currency::VED,
+                },
+            ]
+        );
+        assert!(CurrencyTimeline::for_country(Country::SE)
+            .periods()
+            .is_empty());
+    }
+
+    #[test]
+    fn currency_at() {
+        let timeline = CurrencyTimeline::for_country(Country::HR);
+        assert_eq!(timeline.country(), Country::HR);
+        assert_eq!(timeline.currency_at(Date::new(1990, 1, 1)), None);
+        assert_eq!(
+            timeline.currency_at(Date::new(2022, 12, 31)),
+            Some(Currency::HRK)
+        );
+        assert_eq!(
+            timeline.currency_at(Date::new(2023, 1, 1)),
+            Some(Currency::EUR)
+        );
+    }
+
+    #[cfg(feature = "with-chrono")]
+    #[test]
+    fn currency_at_chrono() {
+        let timeline = CurrencyTimeline::for_country(Country::HR);
+        let date = chrono::NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        assert_eq!(timeline.currency_at(date), Some(Currency::EUR));
+        let ancient = chrono::NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert_eq!(timeline.currency_at(ancient), None);
+    }
+
+    #[cfg(feature = "with-time")]
+    #[test]
+    fn currency_at_time() {
+        let timeline = CurrencyTimeline::for_country(Country::HR);
+        let date = time::Date::from_calendar_date(2022, time::Month::December, 31).unwrap();
+        assert_eq!(timeline.currency_at(date), Some(Currency::HRK));
+    }
+
+    #[test]
+    fn used_by_historically() {
+        for currency in Currency::VARIANTS {
+            let countries = currency.used_by_historically();
+            assert!(currency.used_by().iter().all(|c| countries.contains(c)));
+            assert!(countries.windows(2).all(|w| w[0] < w[1]));
+            for country in countries {
+                let in_timeline = CurrencyTimeline::for_country(country)
+                    .periods()
+                    .iter()
+                    .any(|p| p.currency == *currency);
+                assert!(in_timeline || currency.used_by().contains(&country));
+            }
+        }
+        assert_eq!(Currency::VED.used_by_historically(), vec![Country::VE]);
+    }
+}
@@ -0,0 +1,102 @@
+//! Ordering currencies by how much they are used
+//!
+//! Which currencies matter most depends on the business, so a picker listing
+//! all of them alphabetically is rarely what users want. [`Currency::rank_with`]
+//! puts the currencies counted by a [`UsageStats`] first and keeps the code
+//! order for the long tail of unused ones.
+
+use crate::Currency;
+
+/// A source of per currency usage figures
+///
+/// The figures can be any measure where more means more popular, e.g. the
+/// number of transactions or their total volume.
+///
+/// # Example
+///
+/// ```
+/// use iso_currency::{Currency, UsageStats};
+///
+/// struct Transactions;
+///
+/// impl UsageStats for Transactions {
+///     fn usage(&self, currency: Currency) -> u64 {
+///         match currency.code() {
+///             "EUR" => 500,
+///             "USD" => 300,
+///             _ => 0,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Currency::rank_with(&Transactions)[..2], [Currency::EUR, Currency::USD]);
+/// ```
+pub trait UsageStats {
+    /// Returns how much `currency` is used, `0` if it isn't used at all
+    fn usage(&self, currency: Currency) -> u64;
+}
+
+impl Currency {
+    /// Returns all currencies ordered by descending usage
+    ///
+    /// Currencies with the same usage, including all unused ones, are ordered
+    /// by code. Filter the result with the other
+    /// accessors, e.g. `is_superseded`, to build a picker.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use iso_currency::{Currency, UsageStats};
+    ///
+    /// struct Volumes(HashMap<Currency, u64>);
+    ///
+    /// impl UsageStats for Volumes {
+    ///     fn usage(&self, currency: Currency) -> u64 {
+    ///         self.0.get(&currency).copied().unwrap_or(0)
+    ///     }
+    /// }
+    ///
+    /// let mut volumes = HashMap::new();
+    /// volumes.insert(Currency::GBP, 10);
+    /// volumes.insert(Currency::CHF, 20);
+    ///
+    /// let picker: Vec<Currency> = Currency::rank_with(&Volumes(volumes))
+    ///     .into_iter()
+    ///     .filter(|c| c.is_superseded().is_none())
+    ///     .collect();
+    /// assert_eq!(picker[..2], [Currency::CHF, Currency::GBP]);
+    /// ```
+    pub fn rank_with<S: UsageStats + ?Sized>(stats: &S) -> Vec<Currency> {
+        let mut currencies = Currency::VARIANTS.to_vec();
+        currencies.sort_by_key(|c| (std::cmp::Reverse(stats.usage(*c)), *c));
+        currencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Currency, UsageStats};
+
+    /// Counts how often each currency appears in a list of payments
+    struct Payments(Vec<Currency>);
+
+    impl UsageStats for Payments {
+        fn usage(&self, currency: Currency) -> u64 {
+            self.0.iter().filter(|c| **c == currency).count() as u64
+        }
+    }
+
+    #[test]
+    fn rank_with() {
+        let mut payments = vec![Currency::JPY, Currency::USD, Currency::EUR];
+        payments.extend([Currency::USD, Currency::EUR, Currency::JPY, Currency::EUR]);
+        let stats = Payments(payments);
+
+        let ranked = Currency::rank_with(&stats);
+        assert_eq!(ranked.len(), Currency::VARIANTS.len());
+        assert_eq!(ranked[..3], [Currency::EUR, Currency::JPY, Currency::USD]);
+        // unused currencies keep the enum order
+        assert!(ranked[3..].windows(2).all(|w| w[0] < w[1]));
+    }
+}
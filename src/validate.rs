@@ -0,0 +1,71 @@
+//! Validating string currency fields with derive based validation crates
+
+use crate::Currency;
+
+/// Validates that a string is an ISO 4217 currency code, for `validator`
+///
+/// Use it as custom validation on string fields, e.g.
+/// `#[validate(custom(function = "iso_currency::validate_code"))]`, so request
+/// types don't have to be changed to `Currency`.
+///
+/// # Example
+///
+/// ```
+/// assert!(iso_currency::validate_code("EUR").is_ok());
+/// assert_eq!(iso_currency::validate_code("eur").unwrap_err().code, "currency_code");
+/// ```
+#[cfg(feature = "with-validator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-validator")))]
+pub fn validate_code(code: &str) -> Result<(), validator::ValidationError> {
+    match Currency::from_code(code) {
+        Some(_) => Ok(()),
+        None => Err(validator::ValidationError::new("currency_code")
+            .with_message("not a valid ISO 4217 currency code".into())),
+    }
+}
+
+/// Validates that a string is an ISO 4217 currency code, for `garde`
+///
+/// Use it as a custom rule on string fields, e.g.
+/// `#[garde(custom(iso_currency::garde_code))]`. It works with any context.
+///
+/// # Example
+///
+/// ```
+/// assert!(iso_currency::garde_code("EUR", &()).is_ok());
+/// assert!(iso_currency::garde_code("EURO", &()).is_err());
+/// ```
+#[cfg(feature = "with-garde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-garde")))]
+pub fn garde_code<C: ?Sized>(code: &str, _context: &C) -> garde::Result {
+    match Currency::from_code(code) {
+        Some(_) => Ok(()),
+        None => Err(garde::Error::new("not a valid ISO 4217 currency code")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "with-validator")]
+    #[test]
+    fn validator() {
+        let error = super::validate_code("AAA").unwrap_err();
+        assert_eq!(
+            error.message.as_deref(),
+            Some("not a valid ISO 4217 currency code")
+        );
+        assert!(super::validate_code(&String::from("HRK")).is_ok());
+    }
+
+    #[cfg(feature = "with-garde")]
+    #[test]
+    fn garde() {
+        struct Context;
+
+        assert!(super::garde_code("CHF", &Context).is_ok());
+        assert_eq!(
+            super::garde_code("", &Context).unwrap_err().message(),
+            "not a valid ISO 4217 currency code"
+        );
+    }
+}
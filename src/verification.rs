@@ -0,0 +1,106 @@
+//! Checks of the generated lookup tables
+//!
+//! [`verify`] exhaustively checks every currency at runtime, which also works
+//! under Miri. The same properties are proven for any value of `Currency`
+//! with [Kani](https://model-checking.github.io/kani/):
+//!
+//! ```text
+//! cargo kani --features verification
+//! ```
+
+use crate::Currency;
+
+/// A property of the generated code which doesn't hold for a currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum VerificationError {
+    /// `from_code(code())` doesn't return the currency
+    #[error("{0:?} doesn't round trip through its code")]
+    Code(Currency),
+    /// `from_numeric(numeric())` doesn't return the currency
+    #[error("{0:?} doesn't round trip through its numeric code")]
+    Numeric(Currency),
+}
+
+fn check(currency: Currency) -> Result<(), VerificationError> {
+    if Currency::from_code(currency.code()) != Some(currency) {
+        return Err(VerificationError::Code(currency));
+    }
+    if Currency::from_numeric(currency.numeric()) != Some(currency) {
+        return Err(VerificationError::Numeric(currency));
+    }
+    Ok(())
+}
+
+// calls every generated accessor, a panic in any of them fails the check
+fn exercise(currency: Currency) {
+    let _ = currency.name();
+    let _ = currency.symbol();
+    let _ = currency.used_by();
+    let _ = currency.exponent();
+    let _ = currency.subunit_fraction();
+    let _ = currency.flags();
+    let _ = currency.kind();
+    let _ = currency.latest_with_factor();
+    let _ = currency.superseded_on();
+    let _ = currency.introduced();
+    let _ = currency.stable_hash64();
+}
+
+/// Checks that the code and numeric lookups are bijections
+///
+/// Every accessor of every currency is called on the way, so this also
+/// shows that the generated code doesn't panic.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(iso_currency::verification::verify(), Ok(()));
+/// ```
+pub fn verify() -> Result<(), VerificationError> {
+    for currency in Currency::VARIANTS {
+        exercise(*currency);
+        check(*currency)?;
+    }
+    Ok(())
+}
+
+#[cfg(kani)]
+mod proofs {
+    use crate::Currency;
+
+    #[kani::proof]
+    fn code_round_trip() {
+        let currency: Currency = kani::any();
+        assert!(Currency::from_code(currency.code()) == Some(currency));
+    }
+
+    #[kani::proof]
+    fn numeric_round_trip() {
+        let currency: Currency = kani::any();
+        assert!(Currency::from_numeric(currency.numeric()) == Some(currency));
+    }
+
+    #[kani::proof]
+    fn accessors_dont_panic() {
+        super::exercise(kani::any());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Currency;
+
+    #[test]
+    fn verify() {
+        assert_eq!(super::verify(), Ok(()));
+    }
+
+    #[test]
+    fn variants_are_complete() {
+        let rows = include_str!("../isodata.tsv").lines().count() - 1;
+        assert_eq!(Currency::VARIANTS.len(), rows);
+        assert!(Currency::VARIANTS
+            .windows(2)
+            .all(|w| (w[0] as usize) < (w[1] as usize)));
+    }
+}
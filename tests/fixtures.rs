@@ -0,0 +1,95 @@
+#![cfg(feature = "with-serde")]
+
+use iso_currency::Currency;
+use serde::Deserialize;
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+#[derive(Deserialize)]
+struct StripeCharge {
+    amount: i64,
+    #[serde(with = "iso_currency::serde_helpers::lowercase")]
+    currency: Currency,
+}
+
+#[test]
+fn stripe_charge() {
+    let charge: StripeCharge = serde_json::from_str(&fixture("stripe_charge.json")).unwrap();
+    assert_eq!(charge.currency, Currency::USD);
+    assert!(!charge.currency.stripe_zero_decimal());
+    assert_eq!(charge.amount, 1099);
+
+    let charge: StripeCharge = serde_json::from_str(&fixture("stripe_charge_jpy.json")).unwrap();
+    assert_eq!(charge.currency, Currency::JPY);
+    assert!(charge.currency.stripe_zero_decimal());
+}
+
+#[derive(Deserialize)]
+struct PayPalOrder {
+    purchase_units: Vec<PayPalPurchaseUnit>,
+}
+
+#[derive(Deserialize)]
+struct PayPalPurchaseUnit {
+    amount: PayPalAmount,
+}
+
+#[derive(Deserialize)]
+struct PayPalAmount {
+    currency_code: Currency,
+    value: String,
+}
+
+#[test]
+fn paypal_order() {
+    let order: PayPalOrder = serde_json::from_str(&fixture("paypal_order.json")).unwrap();
+    for unit in order.purchase_units {
+        let currency = unit.amount.currency_code;
+        assert_eq!(
+            unit.amount.value.contains('.'),
+            !currency.paypal_zero_decimal()
+        );
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OpenBankingPayment {
+    data: OpenBankingData,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OpenBankingData {
+    initiation: OpenBankingInitiation,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OpenBankingInitiation {
+    instructed_amount: OpenBankingAmount,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OpenBankingAmount {
+    amount: String,
+    currency: Currency,
+}
+
+#[test]
+fn open_banking_payment() {
+    let payment: OpenBankingPayment =
+        serde_json::from_str(&fixture("open_banking_payment.json")).unwrap();
+    let amount = payment.data.initiation.instructed_amount;
+    assert_eq!(amount.currency, Currency::GBP);
+    let decimals = amount.amount.split('.').nth(1).map_or(0, str::len);
+    assert_eq!(Some(decimals as u16), amount.currency.exponent());
+}
@@ -0,0 +1,65 @@
+//! Reading the crate's own data table
+
+use std::path::Path;
+
+/// A currency as described by one of the compared sources
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub code: String,
+    pub name: String,
+    /// `None` for currencies without a minor unit
+    pub exponent: Option<u16>,
+    /// ISO 3166-1 alpha-2 codes, `None` if the source doesn't list countries
+    pub countries: Option<Vec<String>>,
+    pub superseded: bool,
+}
+
+pub fn read() -> Vec<Entry> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("isodata.tsv");
+    let table = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {}", path.display(), e));
+    parse(&table)
+}
+
+fn parse(table: &str) -> Vec<Entry> {
+    table
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let columns: Vec<&str> = line.split('\t').collect();
+            Entry {
+                code: columns[0].to_owned(),
+                name: columns[2].to_owned(),
+                exponent: columns[6].parse().ok(),
+                countries: Some(
+                    columns[3]
+                        .split(';')
+                        .filter(|c| !c.is_empty())
+                        .map(String::from)
+                        .collect(),
+                ),
+                superseded: columns[7].split(',').any(|f| f.starts_with("superseded")),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse() {
+        let entries = super::parse(
+            "alpha3\tnumeric\tname\tused_by_alpha2\tsymbol\tsubunit_symbol\texponent\tflags\tsuperseded_ratio\tsuperseded_on\tintroduced\n\
+             HRK\t191\tCroatian kuna\tHR\tkn\tlp\t2\tsuperseded(EUR)\t2000/15069\t2023-01-01\t1994-05-30\n\
+             XAU\t959\tGold\t\tXAU\t\t\tspecial\t\t\t",
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].countries, Some(vec!["HR".to_owned()]));
+        assert!(entries[0].superseded);
+        assert_eq!(entries[1].exponent, None);
+        assert_eq!(entries[1].countries, Some(vec![]));
+    }
+}
@@ -0,0 +1,70 @@
+//! Maintenance tasks for the crate's data, run with `cargo xtask`
+
+mod dataset;
+mod report;
+mod six;
+mod wikipedia;
+
+use std::env;
+use std::path::PathBuf;
+use std::process::exit;
+
+const USAGE: &str = "Usage: cargo xtask data-report --six <list-one.xml> [--wikipedia <ISO_4217.wikitext>]
+
+Compares isodata.tsv with the official ISO 4217 list published by SIX and,
+optionally, the tables of the Wikipedia ISO 4217 article and prints all
+differences in exponents and countries. Names are only compared with
+Wikipedia, as SIX uses its own naming style.
+
+The sources are read from local files:
+  curl -o list-one.xml https://www.six-group.com/dam/download/financial-information/data-center/iso-currrency/lists/list-one.xml
+  curl -o ISO_4217.wikitext 'https://en.wikipedia.org/w/index.php?title=ISO_4217&action=raw'";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    if args.next().as_deref() != Some("data-report") {
+        eprintln!("{}", USAGE);
+        exit(2);
+    }
+
+    let mut six = None;
+    let mut wikipedia = None;
+    while let Some(arg) = args.next() {
+        let target = match arg.as_str() {
+            "--six" => &mut six,
+            "--wikipedia" => &mut wikipedia,
+            _ => {
+                eprintln!("{}", USAGE);
+                exit(2);
+            }
+        };
+        *target = args.next().map(PathBuf::from);
+    }
+    let six = six.unwrap_or_else(|| {
+        eprintln!("{}", USAGE);
+        exit(2);
+    });
+
+    let read = |path: &PathBuf| {
+        std::fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("Couldn't read {}: {}", path.display(), e);
+            exit(1);
+        })
+    };
+    let dataset = dataset::read();
+
+    let mut discrepancies = report::compare("SIX", false, &dataset, &six::parse(&read(&six)));
+    if let Some(wikipedia) = wikipedia {
+        discrepancies.extend(report::compare(
+            "Wikipedia",
+            true,
+            &dataset,
+            &wikipedia::parse(&read(&wikipedia)),
+        ));
+    }
+
+    for discrepancy in discrepancies.iter() {
+        println!("{}", discrepancy);
+    }
+    eprintln!("{} discrepancies", discrepancies.len());
+}
@@ -0,0 +1,133 @@
+//! Comparing the dataset with another source
+
+use crate::dataset::Entry;
+
+/// Returns one line per difference between the dataset and a source
+///
+/// Names are only compared, ignoring case, if `compare_names` is set. SIX
+/// uses its own naming style (e.g. "US Dollar" for "United States dollar"),
+/// so its names would differ for nearly every currency. Superseded
+/// currencies are expected to be missing from current sources.
+pub fn compare(
+    source: &str,
+    compare_names: bool,
+    dataset: &[Entry],
+    other: &[Entry],
+) -> Vec<String> {
+    let mut discrepancies = Vec::new();
+    for ours in dataset.iter() {
+        let theirs = match other.iter().find(|e| e.code == ours.code) {
+            Some(theirs) => theirs,
+            None if ours.superseded => continue,
+            None => {
+                discrepancies.push(format!(
+                    "{}\t{}\tmissing from {}",
+                    source, ours.code, source
+                ));
+                continue;
+            }
+        };
+        if compare_names && !ours.name.eq_ignore_ascii_case(&theirs.name) {
+            discrepancies.push(format!(
+                "{}\t{}\tname: {:?} in the dataset, {:?} in {}",
+                source, ours.code, ours.name, theirs.name, source
+            ));
+        }
+        if ours.exponent != theirs.exponent {
+            discrepancies.push(format!(
+                "{}\t{}\texponent: {:?} in the dataset, {:?} in {}",
+                source, ours.code, ours.exponent, theirs.exponent, source
+            ));
+        }
+        if let (Some(our_countries), Some(their_countries)) = (&ours.countries, &theirs.countries) {
+            let missing: Vec<&str> = their_countries
+                .iter()
+                .filter(|c| !our_countries.contains(c))
+                .map(String::as_str)
+                .collect();
+            let extra: Vec<&str> = our_countries
+                .iter()
+                .filter(|c| !their_countries.contains(c))
+                .map(String::as_str)
+                .collect();
+            if !missing.is_empty() || !extra.is_empty() {
+                discrepancies.push(format!(
+                    "{}\t{}\tcountries: missing [{}], not in {} [{}]",
+                    source,
+                    ours.code,
+                    missing.join(", "),
+                    source,
+                    extra.join(", ")
+                ));
+            }
+        }
+    }
+    for theirs in other.iter() {
+        if !dataset.iter().any(|e| e.code == theirs.code) {
+            discrepancies.push(format!("{}\t{}\tnot in the dataset", source, theirs.code));
+        }
+    }
+    discrepancies
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataset::Entry;
+
+    fn entry(code: &str, name: &str, exponent: Option<u16>, countries: &[&str]) -> Entry {
+        Entry {
+            code: code.to_owned(),
+            name: name.to_owned(),
+            exponent,
+            countries: Some(countries.iter().map(|c| c.to_string()).collect()),
+            superseded: false,
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let mut hrk = entry("HRK", "Croatian kuna", Some(2), &["HR"]);
+        hrk.superseded = true;
+        let dataset = vec![
+            entry("EUR", "Euro", Some(2), &["AT", "DE"]),
+            entry("ISK", "Icelandic króna", Some(0), &["IS"]),
+            entry("MGA", "Malagasy ariary", Some(2), &["MG"]),
+            hrk,
+        ];
+        let other = vec![
+            entry("EUR", "EURO", Some(2), &["AT", "HR"]),
+            entry("ISK", "Iceland Krona", Some(0), &["IS"]),
+            entry("ZWG", "Zimbabwe Gold", Some(2), &["ZW"]),
+        ];
+        assert_eq!(
+            super::compare("Wikipedia", true, &dataset, &other),
+            vec![
+                "Wikipedia\tEUR\tcountries: missing [HR], not in Wikipedia [DE]",
+                "Wikipedia\tISK\tname: \"Icelandic króna\" in the dataset, \"Iceland Krona\" in Wikipedia",
+                "Wikipedia\tMGA\tmissing from Wikipedia",
+                "Wikipedia\tZWG\tnot in the dataset",
+            ]
+        );
+    }
+
+    #[test]
+    fn six_names_are_ignored() {
+        // names as published in list-one.xml
+        let dataset = vec![
+            entry("USD", "United States dollar", Some(2), &["US"]),
+            entry("ISK", "Icelandic króna", Some(0), &["IS"]),
+            entry("GBP", "Pound sterling", Some(2), &["GB"]),
+            entry("KRW", "South Korean won", Some(0), &["KR"]),
+        ];
+        let six = vec![
+            entry("USD", "US Dollar", Some(2), &["US"]),
+            entry("ISK", "Iceland Krona", Some(0), &["IS"]),
+            entry("GBP", "Pound Sterling", Some(2), &["GB"]),
+            entry("KRW", "Won", Some(1), &["KR"]),
+        ];
+        assert_eq!(
+            super::compare("SIX", false, &dataset, &six),
+            vec!["SIX\tKRW\texponent: Some(0) in the dataset, Some(1) in SIX"]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Parsing the official ISO 4217 list published by SIX (`list-one.xml`)
+
+use crate::dataset::Entry;
+
+/// Returns the text of the first `<tag>` element in `xml`
+fn element<'a>(xml: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{}", tag);
+    // skip elements which only start with the tag name, e.g. `<CcyNm>` for `Ccy`
+    let start = xml
+        .match_indices(&open)
+        .map(|(i, _)| i)
+        .find(|i| matches!(xml[i + open.len()..].chars().next(), Some('>') | Some(' ')))?;
+    let content = start + xml[start..].find('>')? + 1;
+    let end = content + xml[content..].find(&format!("</{}>", tag))?;
+    Some(xml[content..end].trim())
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Normalizes an ISO 3166 country name for comparison
+fn normalize_country(name: &str) -> String {
+    name.to_uppercase()
+        .replace(" (THE)", "")
+        .replace("(THE)", "")
+        .trim()
+        .to_owned()
+}
+
+/// Looks up the alpha-2 code of a country name as used by SIX
+fn country_code(name: &str) -> Option<String> {
+    let name = normalize_country(name);
+    iso_country::data::all()
+        .into_iter()
+        .find(|country| normalize_country(country.name) == name)
+        .map(|country| country.alpha2.to_owned())
+}
+
+/// Parses the entries of `list-one.xml`, one per currency
+///
+/// Country names which can't be matched to an ISO 3166-1 code are kept as
+/// they are, so they show up as differences in the report.
+pub fn parse(xml: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for entry in xml.split("<CcyNtry>").skip(1) {
+        let entry = &entry[..entry.find("</CcyNtry>").unwrap_or(entry.len())];
+        let code = match element(entry, "Ccy") {
+            Some(code) => code.to_owned(),
+            // countries without a universal currency, e.g. Antarctica
+            None => continue,
+        };
+        let country = element(entry, "CtryNm").map(unescape).unwrap_or_default();
+        let country = country_code(&country).unwrap_or(country);
+
+        match entries.iter_mut().find(|e| e.code == code) {
+            Some(existing) => existing
+                .countries
+                .get_or_insert_with(Vec::new)
+                .push(country),
+            None => entries.push(Entry {
+                code,
+                name: element(entry, "CcyNm").map(unescape).unwrap_or_default(),
+                exponent: element(entry, "CcyMnrUnts").and_then(|e| e.parse().ok()),
+                countries: Some(vec![country]),
+                superseded: false,
+            }),
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    const LIST: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ISO_4217 Pblshd="2024-06-25">
+  <CcyTbl>
+    <CcyNtry>
+      <CtryNm>ANTARCTICA</CtryNm>
+      <CcyNm>No universal currency</CcyNm>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>AUSTRIA</CtryNm>
+      <CcyNm>Euro</CcyNm>
+      <Ccy>EUR</Ccy>
+      <CcyNbr>978</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>NETHERLANDS (THE)</CtryNm>
+      <CcyNm>Euro</CcyNm>
+      <Ccy>EUR</Ccy>
+      <CcyNbr>978</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>BOLIVIA (PLURINATIONAL STATE OF)</CtryNm>
+      <CcyNm IsFund="true">Mvdol</CcyNm>
+      <Ccy>BOV</Ccy>
+      <CcyNbr>984</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>ZZ06_Gold</CtryNm>
+      <CcyNm>Gold</CcyNm>
+      <Ccy>XAU</Ccy>
+      <CcyNbr>959</CcyNbr>
+      <CcyMnrUnts>N.A.</CcyMnrUnts>
+    </CcyNtry>
+  </CcyTbl>
+</ISO_4217>"#;
+
+    #[test]
+    fn parse() {
+        let entries = super::parse(LIST);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].code, "EUR");
+        assert_eq!(
+            entries[0].countries,
+            Some(vec!["AT".to_owned(), "NL".to_owned()])
+        );
+        assert_eq!(entries[1].name, "Mvdol");
+        assert_eq!(entries[1].countries, Some(vec!["BO".to_owned()]));
+        assert_eq!(entries[2].exponent, None);
+        assert_eq!(entries[2].countries, Some(vec!["ZZ06_Gold".to_owned()]));
+    }
+}
@@ -0,0 +1,102 @@
+//! Parsing the tables of the Wikipedia ISO 4217 article from its wikitext
+
+use crate::dataset::Entry;
+
+/// Removes `{{templates}}` and `<ref>` footnotes and resolves `[[links]]`
+fn strip_markup(cell: &str) -> String {
+    let mut text = String::new();
+    let mut rest = cell;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("{{") {
+            rest = after.find("}}").map_or("", |end| &after[end + 2..]);
+        } else if let Some(after) = rest.strip_prefix("<ref") {
+            rest = match (after.find("/>"), after.find("</ref>")) {
+                (Some(short), Some(long)) if short < long => &after[short + 2..],
+                (_, Some(long)) => &after[long + 6..],
+                (Some(short), None) => &after[short + 2..],
+                (None, None) => "",
+            };
+        } else if let Some(after) = rest.strip_prefix("[[") {
+            let end = after.find("]]").unwrap_or(after.len());
+            let link = &after[..end];
+            text.push_str(link.rsplit('|').next().unwrap_or(link));
+            rest = after.get(end + 2..).unwrap_or("");
+        } else {
+            let c = rest.chars().next().unwrap();
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    text.trim().to_owned()
+}
+
+/// Parses the rows of the code tables, the first row of a code wins
+///
+/// Rows start with the code, the numeric code, the number of decimal places
+/// and the name. Countries aren't compared as the article links them by
+/// name in varying forms.
+pub fn parse(wikitext: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for line in wikitext.lines() {
+        let row = match line.strip_prefix('|') {
+            Some(row) if !row.starts_with(['-', '}', '+']) => row,
+            _ => continue,
+        };
+        let cells: Vec<String> = row.split("||").map(strip_markup).collect();
+        if cells.len() < 4 {
+            continue;
+        }
+        let code = &cells[0];
+        if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+            continue;
+        }
+        if cells[1].len() != 3 || !cells[1].bytes().all(|b| b.is_ascii_digit()) {
+            continue;
+        }
+        if entries.iter().any(|e| &e.code == code) {
+            continue;
+        }
+        entries.push(Entry {
+            code: code.clone(),
+            name: cells[3].clone(),
+            exponent: cells[2].parse().ok(),
+            countries: None,
+            superseded: false,
+        });
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn strip_markup() {
+        assert_eq!(super::strip_markup(" {{Anchor|AED}}AED "), "AED");
+        assert_eq!(
+            super::strip_markup("[[United Arab Emirates dirham|UAE dirham]]<ref name=\"a\" />"),
+            "UAE dirham"
+        );
+        assert_eq!(super::strip_markup("[[Euro]]<ref>Footnote</ref> "), "Euro");
+    }
+
+    #[test]
+    fn parse() {
+        let wikitext = "{| class=\"wikitable sortable\"\n\
+            ! Code !! Num !! D !! Currency !! Locations\n\
+            |-\n\
+            | {{Anchor|AED}}AED || 784 || 2 || [[United Arab Emirates dirham]] || {{flag|United Arab Emirates}}\n\
+            |-\n\
+            | {{Anchor|XAU}}XAU || 959 || . || [[Gold]] (one [[troy ounce]]) ||\n\
+            |}\n\
+            {| class=\"wikitable\"\n\
+            |-\n\
+            | AED || 784 || 3 || Old dirham || 1973 ||\n\
+            |}";
+        let entries = super::parse(wikitext);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "United Arab Emirates dirham");
+        assert_eq!(entries[0].exponent, Some(2));
+        assert_eq!(entries[1].name, "Gold (one troy ounce)");
+        assert_eq!(entries[1].exponent, None);
+    }
+}